@@ -0,0 +1,53 @@
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BatchSize;
+use criterion::Criterion;
+
+use tinystr::{TinyStr16, TinyStr4, TinyStr8};
+
+macro_rules! bench_block {
+    ($r:ty, $group:expr, $name:expr) => {
+        // Create about 36000 entries, with 2, 3 and 4 characters, in an order that
+        // is not sorted.
+        let mut strings = Vec::new();
+        for i in 'a'..='z' {
+            for j in 'a'..='z' {
+                let raw = [j as u8, i as u8];
+                strings.push(<$r>::from_bytes(&raw).unwrap());
+                for k in 'a'..='z' {
+                    let raw = [k as u8, j as u8, i as u8];
+                    strings.push(<$r>::from_bytes(&raw).unwrap());
+                    let raw = [k as u8, i as u8, j as u8, k as u8];
+                    strings.push(<$r>::from_bytes(&raw).unwrap());
+                }
+            }
+        }
+
+        $group.bench_function(concat!($name, "/sort_unstable"), |b| {
+            b.iter_batched_ref(
+                || strings.clone(),
+                |s| black_box(s).sort_unstable(),
+                BatchSize::LargeInput,
+            )
+        });
+        $group.bench_function(concat!($name, "/radix_sort"), |b| {
+            b.iter_batched_ref(
+                || strings.clone(),
+                |s| <$r>::radix_sort(black_box(s)),
+                BatchSize::LargeInput,
+            )
+        });
+    };
+}
+
+fn radix_sort_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("radix_sort");
+    bench_block!(TinyStr4, group, "tinystr4");
+    bench_block!(TinyStr8, group, "tinystr8");
+    bench_block!(TinyStr16, group, "tinystr16");
+    group.finish();
+}
+
+criterion_group!(benches, radix_sort_bench);
+criterion_main!(benches);
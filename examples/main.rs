@@ -7,12 +7,12 @@ fn main() {
     assert_eq!(s1.to_ascii_uppercase(), "TEST");
     assert_eq!(s1.to_ascii_lowercase(), "test");
     assert_eq!(s1.to_ascii_titlecase(), "Test");
-    assert_eq!(s1.is_ascii_alphanumeric(), true);
+    assert!(s1.is_ascii_alphanumeric());
 
     let s2: TinyStr8 = "New York".parse().expect("Failed to parse.");
 
     assert_eq!(s2, "New York");
     assert_eq!(s2.to_ascii_uppercase(), "NEW YORK");
     assert_eq!(s2.to_ascii_lowercase(), "new york");
-    assert_eq!(s2.is_ascii_alphanumeric(), false);
+    assert!(!s2.is_ascii_alphanumeric());
 }
@@ -12,7 +12,7 @@ fn get_value_from_token_stream(input: TokenStream) -> String {
     if !val.starts_with('"') && !val.ends_with('"') {
         panic!("Expected a string literal; found {:?}", input);
     }
-    (&val[1..val.len() - 1]).to_string()
+    val[1..val.len() - 1].to_string()
 }
 
 #[proc_macro]
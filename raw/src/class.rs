@@ -0,0 +1,212 @@
+/// The maximum number of ranges in an [`AsciiClass`].
+const MAX_RANGES: usize = 8;
+
+/// A set of ASCII bytes, made of up to 8 inclusive ranges.
+///
+/// Classes are `const`-constructible and are matched against a whole TinyStr word at
+/// once, with a couple of additions per range.
+///
+/// # Examples
+///
+/// ```
+/// use tinystr_raw::AsciiClass;
+///
+/// const IDENT: AsciiClass = AsciiClass::ALPHANUMERIC.union(AsciiClass::byte(b'_'));
+///
+/// assert!(IDENT.contains(b'_'));
+/// assert!(IDENT.contains(b'Z'));
+/// assert!(!IDENT.contains(b'-'));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct AsciiClass {
+    // Only the first `len` ranges are used.
+    ranges: [[u8; 2]; MAX_RANGES],
+    len: u8,
+}
+
+impl AsciiClass {
+    /// The empty class.
+    pub const NONE: Self = Self {
+        ranges: [[0; 2]; MAX_RANGES],
+        len: 0,
+    };
+    /// Every ASCII byte. Constructors still reject NUL.
+    pub const ANY: Self = Self::range(0, 0x7f);
+    /// `A-Z` and `a-z`, as in [`u8::is_ascii_alphabetic`].
+    pub const ALPHABETIC: Self = Self::UPPERCASE.union(Self::LOWERCASE);
+    /// `A-Z`, as in [`u8::is_ascii_uppercase`].
+    pub const UPPERCASE: Self = Self::range(b'A', b'Z');
+    /// `a-z`, as in [`u8::is_ascii_lowercase`].
+    pub const LOWERCASE: Self = Self::range(b'a', b'z');
+    /// `0-9`, as in [`u8::is_ascii_digit`].
+    pub const DIGIT: Self = Self::range(b'0', b'9');
+    /// `0-9`, `A-F` and `a-f`, as in [`u8::is_ascii_hexdigit`].
+    pub const HEXDIGIT: Self = Self::DIGIT
+        .union(Self::range(b'A', b'F'))
+        .union(Self::range(b'a', b'f'));
+    /// `0-9`, `A-Z` and `a-z`, as in [`u8::is_ascii_alphanumeric`].
+    pub const ALPHANUMERIC: Self = Self::DIGIT.union(Self::ALPHABETIC);
+    /// The ASCII punctuation, as in [`u8::is_ascii_punctuation`].
+    pub const PUNCTUATION: Self = Self::range(b'!', b'/')
+        .union(Self::range(b':', b'@'))
+        .union(Self::range(b'[', b'`'))
+        .union(Self::range(b'{', b'~'));
+    /// `!` to `~`, as in [`u8::is_ascii_graphic`].
+    pub const GRAPHIC: Self = Self::range(b'!', b'~');
+    /// The ASCII whitespace, as in [`u8::is_ascii_whitespace`].
+    pub const WHITESPACE: Self = Self::range(b'\t', b'\n')
+        .union(Self::range(0x0c, b'\r'))
+        .union(Self::byte(b' '));
+    /// The ASCII control characters, as in [`u8::is_ascii_control`].
+    pub const CONTROL: Self = Self::range(0, 0x1f).union(Self::byte(0x7f));
+
+    /// Creates a class of the bytes in `lo..=hi`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi` or `hi` is not ASCII.
+    pub const fn range(lo: u8, hi: u8) -> Self {
+        assert!(lo <= hi && hi < 0x80, "invalid ASCII range");
+        let mut result = Self::NONE;
+        result.ranges[0] = [lo, hi];
+        result.len = 1;
+        result
+    }
+
+    /// Creates a class of a single byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` is not ASCII.
+    pub const fn byte(byte: u8) -> Self {
+        Self::range(byte, byte)
+    }
+
+    /// Returns the union of two classes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would have more than 8 ranges.
+    pub const fn union(self, other: Self) -> Self {
+        assert!(
+            self.len as usize + other.len as usize <= MAX_RANGES,
+            "too many ranges in AsciiClass"
+        );
+        let mut result = self;
+        let mut i = 0;
+        while i < other.len as usize {
+            result.ranges[result.len as usize] = other.ranges[i];
+            result.len += 1;
+            i += 1;
+        }
+        result
+    }
+
+    /// Returns `true` if `byte` is in the class.
+    pub const fn contains(self, byte: u8) -> bool {
+        let mut i = 0;
+        while i < self.len as usize {
+            if self.ranges[i][0] <= byte && byte <= self.ranges[i][1] {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+macro_rules! class_mask {
+    ($name:ident, $int:ident) => {
+        impl AsciiClass {
+            /// Returns a mask with `0x80` set in each byte of `word` that is in the class.
+            ///
+            /// The bytes of `word` must all be ASCII; NUL padding bytes are included if
+            /// the class contains NUL.
+            #[inline(always)]
+            pub const fn $name(self, word: $int) -> $int {
+                const ONES: $int = <$int>::MAX / 0xff;
+                let mut mask = 0;
+                let mut i = 0;
+                while i < self.len as usize {
+                    let [lo, hi] = self.ranges[i];
+                    let ge_lo = word + ONES * (0x80 - lo) as $int;
+                    let gt_hi = word + ONES * (0x7f - hi) as $int;
+                    mask |= ge_lo & !gt_hi;
+                    i += 1;
+                }
+                mask & ONES * 0x80
+            }
+        }
+    };
+}
+
+class_mask!(mask_u32, u32);
+class_mask!(mask_u64, u64);
+class_mask!(mask_u128, u128);
+
+#[test]
+fn test_class_contains() {
+    for byte in 0..=0xffu8 {
+        assert_eq!(
+            AsciiClass::ALPHABETIC.contains(byte),
+            byte.is_ascii_alphabetic()
+        );
+        assert_eq!(
+            AsciiClass::UPPERCASE.contains(byte),
+            byte.is_ascii_uppercase()
+        );
+        assert_eq!(
+            AsciiClass::LOWERCASE.contains(byte),
+            byte.is_ascii_lowercase()
+        );
+        assert_eq!(AsciiClass::DIGIT.contains(byte), byte.is_ascii_digit());
+        assert_eq!(
+            AsciiClass::HEXDIGIT.contains(byte),
+            byte.is_ascii_hexdigit()
+        );
+        assert_eq!(
+            AsciiClass::ALPHANUMERIC.contains(byte),
+            byte.is_ascii_alphanumeric()
+        );
+        assert_eq!(
+            AsciiClass::PUNCTUATION.contains(byte),
+            byte.is_ascii_punctuation()
+        );
+        assert_eq!(AsciiClass::GRAPHIC.contains(byte), byte.is_ascii_graphic());
+        assert_eq!(
+            AsciiClass::WHITESPACE.contains(byte),
+            byte.is_ascii_whitespace()
+        );
+        assert_eq!(AsciiClass::CONTROL.contains(byte), byte.is_ascii_control());
+        assert!(!AsciiClass::NONE.contains(byte));
+        assert_eq!(AsciiClass::ANY.contains(byte), byte.is_ascii());
+    }
+}
+
+#[test]
+fn test_class_mask() {
+    let classes = [
+        AsciiClass::NONE,
+        AsciiClass::ALPHABETIC,
+        AsciiClass::HEXDIGIT,
+        AsciiClass::ALPHANUMERIC,
+        AsciiClass::PUNCTUATION,
+        AsciiClass::GRAPHIC,
+        AsciiClass::WHITESPACE,
+        AsciiClass::CONTROL,
+    ];
+    for class in classes.iter() {
+        for byte in 0..0x80u8 {
+            let word = u32::from_le_bytes([byte, b'a', byte, 0]);
+            let expected = u32::from_le_bytes([
+                if class.contains(byte) { 0x80 } else { 0 },
+                if class.contains(b'a') { 0x80 } else { 0 },
+                if class.contains(byte) { 0x80 } else { 0 },
+                if class.contains(0) { 0x80 } else { 0 },
+            ]);
+            assert_eq!(class.mask_u32(word), expected);
+            assert_eq!(class.mask_u64(word as u64) as u32, expected);
+            assert_eq!(class.mask_u128(word as u128) as u32, expected);
+        }
+    }
+}
@@ -4,25 +4,211 @@ use core::fmt;
 use std::error;
 
 /// Enum to store the various types of errors that can cause parsing a TinyStr to fail.
-#[derive(PartialEq, Eq, Debug)]
+///
+/// Indices are byte offsets into the input. For a byte passed as an argument, such as
+/// a fill or separator byte, the index is where it would have been stored.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub enum Error {
-    /// String is too large or too small to store as TinyStr.
-    InvalidSize,
     /// String is empty.
-    InvalidNull,
+    Empty,
+    /// String is too long to store as TinyStr.
+    TooLong {
+        /// The length of the string, in bytes.
+        len: usize,
+        /// The maximum length, in bytes.
+        max: usize,
+    },
+    /// String contains a NUL byte.
+    InteriorNull {
+        /// The index of the first NUL byte.
+        index: usize,
+    },
     /// String contains non-ASCII character(s).
-    NonAscii,
+    NonAscii {
+        /// The index of the first non-ASCII byte.
+        index: usize,
+        /// The non-ASCII byte.
+        byte: u8,
+    },
+    /// String does not pass the validation of a type defined with `define_tinystr_type!`.
+    InvalidFormat,
+    /// String does not have the exact length required by an `ExactTinyStr`.
+    LengthMismatch {
+        /// The length of the string, in bytes.
+        len: usize,
+        /// The required length, in bytes.
+        expected: usize,
+    },
+    /// A slice of fixed-size values, such as a `zerovec` byte slice, has a length that is
+    /// not a multiple of the value size.
+    InvalidSliceLength {
+        /// The length of the slice, in bytes.
+        len: usize,
+        /// The size of each value, in bytes.
+        size: usize,
+    },
+    /// A fixed-size buffer, such as a `TinyBytes`, has a non-NUL byte in the padding after
+    /// its content.
+    InvalidPadding {
+        /// The index of the first non-NUL padding byte.
+        index: usize,
+        /// The padding byte.
+        byte: u8,
+    },
+    /// String contains a character outside of the class allowed by the constructor.
+    DisallowedCharacter {
+        /// The index of the first disallowed byte.
+        index: usize,
+        /// The disallowed byte.
+        byte: u8,
+    },
+    /// An index passed as an argument is out of bounds.
+    IndexOutOfBounds {
+        /// The index.
+        index: usize,
+        /// The length of the string, in bytes.
+        len: usize,
+    },
+}
+
+impl Error {
+    /// Returns `Error::Empty` if `len` is zero, or else `Error::TooLong`.
+    ///
+    /// This is meant for the error path, once a length check has failed.
+    #[doc(hidden)]
+    #[cold]
+    pub const fn invalid_size(len: usize, max: usize) -> Self {
+        if len == 0 {
+            Error::Empty
+        } else {
+            Error::TooLong { len, max }
+        }
+    }
+
+    /// Returns the error for the first non-ASCII byte of `bytes`, or else the first NUL
+    /// byte, with indices starting at `offset`.
+    ///
+    /// This is meant for the error path, once a word check has found an invalid byte.
+    #[doc(hidden)]
+    #[cold]
+    pub const fn invalid_byte(bytes: &[u8], offset: usize) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] >= 0x80 {
+                return Error::NonAscii {
+                    index: offset + i,
+                    byte: bytes[i],
+                };
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while i < bytes.len() && bytes[i] != 0 {
+            i += 1;
+        }
+        Error::InteriorNull { index: offset + i }
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Error::InvalidSize => write!(f, "invalid size"),
-            Error::InvalidNull => write!(f, "string is empty"),
-            Error::NonAscii => write!(f, "contains non-ASCII"),
+        match *self {
+            Error::Empty => write!(f, "string is empty"),
+            Error::TooLong { len, max } => write!(
+                f,
+                "string is too long: {} bytes, expected at most {} bytes",
+                len, max
+            ),
+            Error::InteriorNull { index } => write!(f, "NUL byte at index {}", index),
+            Error::NonAscii { index, byte } => {
+                write!(f, "non-ASCII byte 0x{:02x} at index {}", byte, index)
+            }
+            Error::InvalidFormat => write!(f, "invalid format"),
+            Error::LengthMismatch { len, expected } => write!(
+                f,
+                "length mismatch: {} bytes, expected exactly {} bytes",
+                len, expected
+            ),
+            Error::InvalidSliceLength { len, size } => write!(
+                f,
+                "invalid slice length: {} bytes, expected a multiple of {} bytes",
+                len, size
+            ),
+            Error::InvalidPadding { index, byte } => {
+                write!(f, "non-NUL padding byte 0x{:02x} at index {}", byte, index)
+            }
+            Error::DisallowedCharacter { index, byte } => write!(
+                f,
+                "disallowed character '{}' at index {}",
+                core::ascii::escape_default(byte),
+                index
+            ),
+            Error::IndexOutOfBounds { index, len } => write!(
+                f,
+                "index {} is out of bounds for a string of {} bytes",
+                index, len
+            ),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl error::Error for Error {}
+
+#[test]
+fn test_display() {
+    extern crate std;
+    use std::string::ToString;
+
+    assert_eq!(
+        Error::TooLong { len: 5, max: 4 }.to_string(),
+        "string is too long: 5 bytes, expected at most 4 bytes"
+    );
+    assert_eq!(Error::Empty.to_string(), "string is empty");
+    assert_eq!(
+        Error::InteriorNull { index: 1 }.to_string(),
+        "NUL byte at index 1"
+    );
+    assert_eq!(
+        Error::NonAscii {
+            index: 2,
+            byte: 0xc3
+        }
+        .to_string(),
+        "non-ASCII byte 0xc3 at index 2"
+    );
+    assert_eq!(
+        Error::InvalidSliceLength { len: 10, size: 4 }.to_string(),
+        "invalid slice length: 10 bytes, expected a multiple of 4 bytes"
+    );
+    assert_eq!(
+        Error::InvalidPadding {
+            index: 3,
+            byte: 0x61
+        }
+        .to_string(),
+        "non-NUL padding byte 0x61 at index 3"
+    );
+    assert_eq!(
+        Error::DisallowedCharacter {
+            index: 1,
+            byte: b'\t'
+        }
+        .to_string(),
+        "disallowed character '\\t' at index 1"
+    );
+    assert_eq!(
+        Error::invalid_byte(b"ab\0", 0),
+        Error::InteriorNull { index: 2 }
+    );
+    assert_eq!(Error::invalid_size(0, 4), Error::Empty);
+    assert_eq!(Error::invalid_size(9, 8), Error::TooLong { len: 9, max: 8 });
+    assert_eq!(
+        Error::invalid_byte(b"ab\0\xff", 1),
+        Error::NonAscii {
+            index: 4,
+            byte: 0xff
+        }
+    );
+}
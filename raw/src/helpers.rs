@@ -13,11 +13,8 @@ pub(crate) unsafe fn make_u32_bytes(
     let mask = u32::from_le(mask);
     let mut word: u32 = 0;
     copy_nonoverlapping(bytes.as_ptr(), &mut word as *mut u32 as *mut u8, len);
-    if (word & mask) != 0 {
-        return Err(Error::NonAscii);
-    }
-    if ((mask - word) & mask) != 0 {
-        return Err(Error::InvalidNull);
+    if (word & mask) != 0 || ((mask - word) & mask) != 0 {
+        return Err(Error::invalid_byte(bytes, 0));
     }
     Ok(NonZeroU32::new_unchecked(word))
 }
@@ -33,11 +30,8 @@ pub(crate) unsafe fn make_u64_bytes(
     let mask = u64::from_le(mask);
     let mut word: u64 = 0;
     copy_nonoverlapping(bytes.as_ptr(), &mut word as *mut u64 as *mut u8, len);
-    if (word & mask) != 0 {
-        return Err(Error::NonAscii);
-    }
-    if ((mask - word) & mask) != 0 {
-        return Err(Error::InvalidNull);
+    if (word & mask) != 0 || ((mask - word) & mask) != 0 {
+        return Err(Error::invalid_byte(bytes, 0));
     }
     Ok(NonZeroU64::new_unchecked(word))
 }
@@ -53,11 +47,8 @@ pub(crate) unsafe fn make_u128_bytes(
     let mask = u128::from_le(mask);
     let mut word: u128 = 0;
     copy_nonoverlapping(bytes.as_ptr(), &mut word as *mut u128 as *mut u8, len);
-    if (word & mask) != 0 {
-        return Err(Error::NonAscii);
-    }
-    if ((mask - word) & mask) != 0 {
-        return Err(Error::InvalidNull);
+    if (word & mask) != 0 || ((mask - word) & mask) != 0 {
+        return Err(Error::invalid_byte(bytes, 0));
     }
     Ok(NonZeroU128::new_unchecked(word))
 }
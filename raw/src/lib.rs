@@ -4,13 +4,51 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod class;
 mod error;
 mod helpers;
+mod normalize;
 
+pub use class::AsciiClass;
 pub use error::Error;
+pub use normalize::{
+    try_u128_from_bytes_with, try_u32_from_bytes_with, try_u64_from_bytes_with, AsciiCase,
+};
 
 use core::num::{NonZeroU128, NonZeroU32, NonZeroU64};
 
+/// Returns the length of the content of a NUL-padded buffer, such as the bytes of a
+/// stored TinyStr.
+///
+/// Returns `Error::InteriorNull` if a non-NUL byte follows the first NUL. The content
+/// itself is not checked.
+#[inline]
+pub const fn nul_padded_len(bytes: &[u8]) -> Result<usize, Error> {
+    let mut len = 0;
+    while len < bytes.len() && bytes[len] != 0 {
+        len += 1;
+    }
+    let mut i = len;
+    while i < bytes.len() {
+        if bytes[i] != 0 {
+            return Err(Error::InteriorNull { index: len });
+        }
+        i += 1;
+    }
+    Ok(len)
+}
+
+#[test]
+fn test_nul_padded_len() {
+    assert_eq!(nul_padded_len(b"ab\0\0"), Ok(2));
+    assert_eq!(nul_padded_len(b"abcd"), Ok(4));
+    assert_eq!(nul_padded_len(b"\0\0\0\0"), Ok(0));
+    assert_eq!(
+        nul_padded_len(b"a\0b\0"),
+        Err(Error::InteriorNull { index: 1 })
+    );
+}
+
 #[inline(always)]
 pub fn try_u32_from_bytes(bytes: &[u8]) -> Result<NonZeroU32, Error> {
     unsafe {
@@ -19,7 +57,7 @@ pub fn try_u32_from_bytes(bytes: &[u8]) -> Result<NonZeroU32, Error> {
             2 => helpers::make_u32_bytes(bytes, 2, 0x8080),
             3 => helpers::make_u32_bytes(bytes, 3, 0x0080_8080),
             4 => helpers::make_u32_bytes(bytes, 4, 0x8080_8080),
-            _ => Err(Error::InvalidSize),
+            len => Err(Error::invalid_size(len, 4)),
         }
     }
 }
@@ -41,7 +79,7 @@ fn test_u32_from_bytes() {
 pub fn try_u64_from_bytes(bytes: &[u8]) -> Result<NonZeroU64, Error> {
     let len = bytes.len();
     if !(1..=8).contains(&len) {
-        return Err(Error::InvalidSize);
+        return Err(Error::invalid_size(len, 8));
     }
     let mask = 0x8080_8080_8080_8080_u64 >> (8 * (8 - len));
     unsafe { helpers::make_u64_bytes(bytes, len, mask) }
@@ -64,7 +102,7 @@ fn test_u64_from_bytes() {
 pub fn try_u128_from_bytes(bytes: &[u8]) -> Result<NonZeroU128, Error> {
     let len = bytes.len();
     if !(1..=16).contains(&len) {
-        return Err(Error::InvalidSize);
+        return Err(Error::invalid_size(len, 16));
     }
     let mask = 0x8080_8080_8080_8080_8080_8080_8080_8080_u128 >> (8 * (16 - len));
     unsafe { helpers::make_u128_bytes(bytes, len, mask) }
@@ -0,0 +1,118 @@
+use core::num::{NonZeroU128, NonZeroU32, NonZeroU64};
+
+use crate::{try_u128_from_bytes, try_u32_from_bytes, try_u64_from_bytes, AsciiClass, Error};
+
+/// The ASCII case mapping applied by the fused constructors.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AsciiCase {
+    /// Keep the bytes as they are.
+    Unchanged,
+    /// Map `A-Z` to `a-z`.
+    Lowercase,
+    /// Map `a-z` to `A-Z`.
+    Uppercase,
+    /// Map the first byte to uppercase and the others to lowercase.
+    Titlecase,
+}
+
+macro_rules! try_from_bytes_with {
+    ($name:ident, $try_from_bytes:ident, $nonzero:ident, $int:ident, $mask:ident) => {
+        /// Loads `bytes` like the plain constructor, then checks that every byte is in
+        /// `class` and applies `case`, with mask operations on the loaded word.
+        ///
+        /// Returns `Error::DisallowedCharacter` if a byte is not in `class`.
+        #[inline(always)]
+        pub fn $name(bytes: &[u8], class: AsciiClass, case: AsciiCase) -> Result<$nonzero, Error> {
+            const HIGH: $int = <$int>::MAX / 0xff * 0x80;
+            const FIRST: $int = 0x80;
+            // Work on the little-endian word, with the first byte in the low bits.
+            let word = $try_from_bytes(bytes)?.get().to_le();
+            let content = HIGH >> (<$int>::BITS as usize - 8 * bytes.len());
+            let disallowed = content & !class.$mask(word);
+            if disallowed != 0 {
+                let index = (disallowed.trailing_zeros() / 8) as usize;
+                return Err(Error::DisallowedCharacter {
+                    index,
+                    byte: bytes[index],
+                });
+            }
+            let word = match case {
+                AsciiCase::Unchanged => word,
+                AsciiCase::Lowercase => word | (AsciiClass::UPPERCASE.$mask(word) >> 2),
+                AsciiCase::Uppercase => word & !(AsciiClass::LOWERCASE.$mask(word) >> 2),
+                AsciiCase::Titlecase => {
+                    let lower = word | (AsciiClass::UPPERCASE.$mask(word) >> 2);
+                    lower & !((AsciiClass::LOWERCASE.$mask(lower) & FIRST) >> 2)
+                }
+            };
+            // The case mappings keep the bytes ASCII and non-NUL.
+            Ok(unsafe { $nonzero::new_unchecked(<$int>::from_le(word)) })
+        }
+    };
+}
+
+try_from_bytes_with!(
+    try_u32_from_bytes_with,
+    try_u32_from_bytes,
+    NonZeroU32,
+    u32,
+    mask_u32
+);
+try_from_bytes_with!(
+    try_u64_from_bytes_with,
+    try_u64_from_bytes,
+    NonZeroU64,
+    u64,
+    mask_u64
+);
+try_from_bytes_with!(
+    try_u128_from_bytes_with,
+    try_u128_from_bytes,
+    NonZeroU128,
+    u128,
+    mask_u128
+);
+
+#[test]
+fn test_from_bytes_with() {
+    let cases = [
+        (AsciiCase::Unchanged, "aBc1"),
+        (AsciiCase::Lowercase, "abc1"),
+        (AsciiCase::Uppercase, "ABC1"),
+        (AsciiCase::Titlecase, "Abc1"),
+    ];
+    for &(case, expected) in cases.iter() {
+        let word = try_u32_from_bytes_with(b"aBc1", AsciiClass::ALPHANUMERIC, case).unwrap();
+        assert_eq!(&word.get().to_ne_bytes(), expected.as_bytes());
+        let word = try_u64_from_bytes_with(b"aBc1", AsciiClass::ALPHANUMERIC, case).unwrap();
+        assert_eq!(&word.get().to_ne_bytes()[..4], expected.as_bytes());
+        let word = try_u128_from_bytes_with(b"aBc1", AsciiClass::ALPHANUMERIC, case).unwrap();
+        assert_eq!(&word.get().to_ne_bytes()[..4], expected.as_bytes());
+    }
+    assert_eq!(
+        try_u32_from_bytes_with(b"aBc1", AsciiClass::ALPHABETIC, AsciiCase::Lowercase),
+        Err(Error::DisallowedCharacter {
+            index: 3,
+            byte: b'1'
+        })
+    );
+    assert_eq!(
+        try_u64_from_bytes_with(b"a\0", AsciiClass::ANY, AsciiCase::Lowercase),
+        Err(Error::InteriorNull { index: 1 })
+    );
+    assert_eq!(
+        try_u128_from_bytes_with(b"a\xff", AsciiClass::ANY, AsciiCase::Lowercase),
+        Err(Error::NonAscii {
+            index: 1,
+            byte: 0xff
+        })
+    );
+    assert_eq!(
+        try_u64_from_bytes_with(b"", AsciiClass::ANY, AsciiCase::Lowercase),
+        Err(Error::Empty)
+    );
+    assert_eq!(
+        try_u32_from_bytes_with(b"12345", AsciiClass::ANY, AsciiCase::Lowercase),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+}
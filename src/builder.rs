@@ -0,0 +1,198 @@
+use std::fmt;
+use std::ops::Deref;
+
+use crate::mutation::check_byte;
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+/// A fixed-capacity buffer for building a TinyStr of up to `N` characters.
+///
+/// `TinyStrBuilder` implements [`fmt::Write`], so it can be used with `write!`. Writing
+/// fails with `fmt::Error`, leaving the buffer unchanged, if the text is not ASCII,
+/// contains NUL, or does not fit. For `N` of 4, 8 and 16, `build` returns the matching
+/// [`TinyStr4`], [`TinyStr8`] or [`TinyStr16`].
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+/// use tinystr::{tinystr8, TinyStrBuilder};
+///
+/// let mut builder = TinyStrBuilder::<8>::new();
+/// write!(builder, "{}{:02}", "AB", 7).expect("Failed to write.");
+///
+/// assert_eq!(builder.build(), Ok(tinystr8!("AB07")));
+/// assert!(write!(builder, "{}", "too long").is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TinyStrBuilder<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> TinyStrBuilder<N> {
+    /// Creates an empty builder.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Creates a builder from the bytes of an iterator.
+    ///
+    /// Returns an error if a byte is NUL or non-ASCII, or if there are more than `N`
+    /// bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::{Error, TinyStrBuilder};
+    ///
+    /// let b1 = TinyStrBuilder::<4>::try_from_iter(b"abc".iter().copied())
+    ///     .expect("Failed to build.");
+    ///
+    /// assert_eq!(b1, "abc");
+    /// assert_eq!(
+    ///     TinyStrBuilder::<4>::try_from_iter(b"abcde".iter().copied()),
+    ///     Err(Error::TooLong { len: 5, max: 4 })
+    /// );
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Result<Self, Error> {
+        let mut result = Self::new();
+        result.try_extend(iter)?;
+        Ok(result)
+    }
+
+    /// Appends a byte.
+    ///
+    /// Returns an error if `byte` is NUL or non-ASCII, or if the builder is full.
+    #[inline]
+    pub fn try_push(&mut self, byte: u8) -> Result<(), Error> {
+        check_byte(byte, self.len)?;
+        if self.len == N {
+            return Err(Error::TooLong { len: N + 1, max: N });
+        }
+        self.bytes[self.len] = byte;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends the bytes of an iterator.
+    ///
+    /// Returns an error if a byte is NUL or non-ASCII, or if the builder becomes full.
+    /// The bytes before the failing one are kept, and the rest of the iterator is not
+    /// consumed, so `Error::TooLong` reports `N + 1` as the length.
+    pub fn try_extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) -> Result<(), Error> {
+        for byte in iter {
+            self.try_push(byte)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bytes written so far.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all the written bytes.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Extracts a string slice containing the bytes written so far.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.deref()
+    }
+}
+
+impl<const N: usize> Default for TinyStrBuilder<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for TinyStrBuilder<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.deref())
+    }
+}
+
+impl<const N: usize> Deref for TinyStrBuilder<N> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        // Only non-NUL ASCII bytes are ever written.
+        unsafe { std::str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for TinyStrBuilder<N> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.deref() == *other
+    }
+}
+
+impl<const N: usize> fmt::Write for TinyStrBuilder<N> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let bytes = text.as_bytes();
+        if bytes.len() > N - self.len || bytes.iter().any(|&b| b == 0 || b >= 0x80) {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+}
+
+macro_rules! impl_builder {
+    ($ty:ident, $len:literal) => {
+        impl TinyStrBuilder<$len> {
+            #[doc = concat!("Returns the written bytes as a [`", stringify!($ty), "`].")]
+            ///
+            /// Returns `Error::Empty` if nothing has been written.
+            #[inline]
+            pub fn build(&self) -> Result<$ty, Error> {
+                $ty::from_bytes(self.as_bytes())
+            }
+        }
+
+        impl $ty {
+            #[doc = concat!("Creates a ", stringify!($ty), " from the bytes of an iterator.")]
+            ///
+            /// Returns an error if a byte is NUL or non-ASCII, or if there are too many
+            /// or no bytes.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1 = ", stringify!($ty), "::try_from_iter(b\"abc\".iter().map(u8::to_ascii_uppercase))")]
+            ///     .expect("Failed to build.");
+            ///
+            /// assert_eq!(s1, "ABC");
+            /// ```
+            #[inline]
+            pub fn try_from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Result<Self, Error> {
+                TinyStrBuilder::<$len>::try_from_iter(iter)?.build()
+            }
+        }
+    };
+}
+
+impl_builder!(TinyStr4, 4);
+impl_builder!(TinyStr8, 8);
+impl_builder!(TinyStr16, 16);
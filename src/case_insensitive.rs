@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+use crate::swar::{w128, w32, w64};
+use crate::{TinyStr16, TinyStr4, TinyStr8};
+
+/// A wrapper whose `Eq`, `Ord` and `Hash` ignore ASCII case.
+///
+/// This lets a TinyStr be used as a case-insensitive key in maps and sets. It is
+/// implemented for [`TinyStr4`], [`TinyStr8`] and [`TinyStr16`].
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use tinystr::{tinystr8, AsciiCaseInsensitive};
+///
+/// let mut set = HashSet::new();
+/// set.insert(AsciiCaseInsensitive(tinystr8!("en-US")));
+///
+/// assert!(set.contains(&AsciiCaseInsensitive(tinystr8!("EN-us"))));
+/// assert!(!set.contains(&AsciiCaseInsensitive(tinystr8!("en-GB"))));
+/// ```
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct AsciiCaseInsensitive<T>(pub T);
+
+impl<T> AsciiCaseInsensitive<T> {
+    /// Returns the wrapped value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for AsciiCaseInsensitive<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for AsciiCaseInsensitive<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for AsciiCaseInsensitive<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for AsciiCaseInsensitive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("AsciiCaseInsensitive")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+macro_rules! impl_case_insensitive {
+    ($ty:ident, $swar:ident, $example:literal) => {
+        impl $ty {
+            /// Checks that two strings are an ASCII case-insensitive match.
+            ///
+            /// Another TinyStr of any width can be passed by reference, as it dereferences
+            /// to `&str`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert!(s1.eq_ignore_ascii_case(\"", $example, "\"));")]
+            /// assert!(s1.eq_ignore_ascii_case(&s1.to_ascii_uppercase()));
+            /// assert!(!s1.eq_ignore_ascii_case("?"));
+            /// ```
+            #[inline]
+            pub const fn eq_ignore_ascii_case(self, other: &str) -> bool {
+                if other.len() != self.len() {
+                    return false;
+                }
+                let other = match $swar::from_bytes(other.as_bytes()) {
+                    Some(other) => other,
+                    None => return false,
+                };
+                // Non-ASCII bytes never match, and would break the lowercase mapping.
+                if other & $swar::HIGH != 0 {
+                    return false;
+                }
+                $swar::to_lowercase(self.as_unsigned().to_le()) == $swar::to_lowercase(other)
+            }
+
+            /// Compares two strings, ignoring ASCII case.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use std::cmp::Ordering;
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"abc\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"ABD\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.cmp(&s2), Ordering::Greater);
+            /// assert_eq!(s1.cmp_ignore_ascii_case(s2), Ordering::Less);
+            /// assert_eq!(s1.cmp_ignore_ascii_case(s1.to_ascii_uppercase()), Ordering::Equal);
+            /// ```
+            #[inline]
+            pub const fn cmp_ignore_ascii_case(self, other: Self) -> Ordering {
+                // Swapping the little-endian words puts the first byte in the most
+                // significant position, so integer order matches string order.
+                let a = $swar::to_lowercase(self.as_unsigned().to_le()).swap_bytes();
+                let b = $swar::to_lowercase(other.as_unsigned().to_le()).swap_bytes();
+                if a < b {
+                    Ordering::Less
+                } else if a > b {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            }
+
+            /// Feeds the ASCII lowercase form of the string into `state`, so that
+            /// strings that are equal ignoring ASCII case hash the same.
+            #[inline]
+            pub fn hash_ignore_ascii_case<H: Hasher>(self, state: &mut H) {
+                self.to_ascii_lowercase().hash(state);
+            }
+        }
+
+        impl PartialEq for AsciiCaseInsensitive<$ty> {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                self.0.cmp_ignore_ascii_case(other.0) == Ordering::Equal
+            }
+        }
+
+        impl Eq for AsciiCaseInsensitive<$ty> {}
+
+        impl PartialOrd for AsciiCaseInsensitive<$ty> {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for AsciiCaseInsensitive<$ty> {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp_ignore_ascii_case(other.0)
+            }
+        }
+
+        impl Hash for AsciiCaseInsensitive<$ty> {
+            #[inline(always)]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash_ignore_ascii_case(state);
+            }
+        }
+    };
+}
+
+impl_case_insensitive!(TinyStr4, w32, "Test");
+impl_case_insensitive!(TinyStr8, w64, "Testing");
+impl_case_insensitive!(TinyStr16, w128, "Metamorphosis");
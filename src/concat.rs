@@ -0,0 +1,198 @@
+use std::ops::Add;
+
+use crate::mutation::check_byte;
+use crate::swar::{w128, w32, w64};
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+macro_rules! impl_concat {
+    ($ty:ident, $int:ident, $swar:ident, $example:literal) => {
+        impl $ty {
+            /// Returns the length of `parts` joined with a separator, for errors.
+            #[cold]
+            const fn joined_len(parts: &[Self]) -> usize {
+                let mut len = parts.len() - 1;
+                let mut i = 0;
+                while i < parts.len() {
+                    len += parts[i].len();
+                    i += 1;
+                }
+                len
+            }
+
+            /// Appends `other`, returning `Error::TooLong` if the result does not fit.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"1\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert_eq!(s1.try_concat(s2), Ok(\"", $example, "1\".parse().unwrap()));")]
+            /// assert!(matches!(s1.try_concat(s1), Err(Error::TooLong { .. })));
+            /// ```
+            #[inline]
+            pub const fn try_concat(self, other: Self) -> Result<Self, Error> {
+                let word = self.as_unsigned().to_le();
+                let other = other.as_unsigned().to_le();
+                let len = $swar::len(word) + $swar::len(other);
+                if len > $swar::LEN {
+                    return Err(Error::TooLong {
+                        len,
+                        max: $swar::LEN,
+                    });
+                }
+                let len = $swar::len(word);
+                let result = word | $swar::shl_bytes(other, len);
+                Ok(unsafe { Self::from_native_unchecked($int::from_le(result)) })
+            }
+
+            /// Appends `separator` and `other`, returning an error if the result does not
+            /// fit or `separator` is not a non-NUL ASCII character.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"en\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"US\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("let s3 = ", stringify!($ty), "::join(&[s1, s2], b'-');")]
+            /// assert_eq!(s1.concat_with_separator(b'-', s2), s3);
+            /// assert_eq!(s1.concat_with_separator(0, s2), Err(Error::InteriorNull { index: 2 }));
+            /// ```
+            #[inline]
+            pub const fn concat_with_separator(self, separator: u8, other: Self) -> Result<Self, Error> {
+                Self::join(&[self, other], separator)
+            }
+
+            /// Concatenates `parts`, placing `separator` between each of them.
+            ///
+            /// Returns an error if `parts` is empty, if the result does not fit, or if
+            /// `separator` is not a non-NUL ASCII character.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            /// let parts = [
+            #[doc = concat!("    \"a\".parse::<", stringify!($ty), ">().unwrap(),")]
+            #[doc = concat!("    \"b\".parse::<", stringify!($ty), ">().unwrap(),")]
+            /// ];
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($ty), "::join(&parts, b'_').unwrap(), \"a_b\");")]
+            #[doc = concat!("assert_eq!(", stringify!($ty), "::join(&[], b'_'), Err(Error::Empty));")]
+            /// ```
+            pub const fn join(parts: &[Self], separator: u8) -> Result<Self, Error> {
+                if parts.is_empty() {
+                    if let Err(e) = check_byte(separator, 0) {
+                        return Err(e);
+                    }
+                    return Err(Error::Empty);
+                }
+                let mut result = parts[0].as_unsigned().to_le();
+                let mut len = $swar::len(result);
+                if let Err(e) = check_byte(separator, len) {
+                    return Err(e);
+                }
+                let mut i = 1;
+                while i < parts.len() {
+                    let part = parts[i].as_unsigned().to_le();
+                    let part_len = $swar::len(part);
+                    if len + 1 + part_len > $swar::LEN {
+                        return Err(Error::TooLong {
+                            len: Self::joined_len(parts),
+                            max: $swar::LEN,
+                        });
+                    }
+                    result |= $swar::shl_bytes(separator as $int | (part << 8), len);
+                    len += 1 + part_len;
+                    i += 1;
+                }
+                Ok(unsafe { Self::from_native_unchecked($int::from_le(result)) })
+            }
+        }
+    };
+}
+
+impl_concat!(TinyStr4, u32, w32, "Tst");
+impl_concat!(TinyStr8, u64, w64, "Testing");
+impl_concat!(TinyStr16, u128, w128, "Metamorphosis");
+
+macro_rules! impl_widening_concat {
+    ($lhs:ident, $lhs_swar:ident, $rhs:ident, $out:ident, $out_int:ident) => {
+        /// Concatenates two TinyStrs into a wider one that is always large enough.
+        impl Add<$rhs> for $lhs {
+            type Output = $out;
+
+            #[inline(always)]
+            fn add(self, other: $rhs) -> $out {
+                let word = self.as_unsigned().to_le();
+                let shift = 8 * $lhs_swar::len(word);
+                let result =
+                    word as $out_int | ((other.as_unsigned().to_le() as $out_int) << shift);
+                unsafe { $out::from_native_unchecked($out_int::from_le(result)) }
+            }
+        }
+    };
+}
+
+impl_widening_concat!(TinyStr4, w32, TinyStr4, TinyStr8, u64);
+impl_widening_concat!(TinyStr4, w32, TinyStr8, TinyStr16, u128);
+impl_widening_concat!(TinyStr8, w64, TinyStr4, TinyStr16, u128);
+impl_widening_concat!(TinyStr8, w64, TinyStr8, TinyStr16, u128);
+
+impl TinyStr4 {
+    /// Appends `other`, returning a `TinyStr8`, which is always large enough.
+    ///
+    /// This is the same as `self + other`, but usable in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::{tinystr4, TinyStr4, TinyStr8};
+    ///
+    /// const S: TinyStr8 = tinystr4!("en").concat(tinystr4!("-US"));
+    ///
+    /// assert_eq!(S, "en-US");
+    /// assert_eq!(tinystr4!("en") + tinystr4!("-US"), S);
+    /// ```
+    #[inline]
+    pub const fn concat(self, other: TinyStr4) -> TinyStr8 {
+        let word = self.as_unsigned().to_le();
+        let shift = 8 * w32::len(word);
+        let result = word as u64 | ((other.as_unsigned().to_le() as u64) << shift);
+        unsafe { TinyStr8::from_native_unchecked(u64::from_le(result)) }
+    }
+}
+
+impl TinyStr8 {
+    /// Appends `other`, returning a `TinyStr16`, which is always large enough.
+    ///
+    /// This is the same as `self + other`, but usable in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::{tinystr8, TinyStr16, TinyStr8};
+    ///
+    /// const S: TinyStr16 = tinystr8!("zh-Hant").concat(tinystr8!("-TW"));
+    ///
+    /// assert_eq!(S, "zh-Hant-TW");
+    /// assert_eq!(tinystr8!("zh-Hant") + tinystr8!("-TW"), S);
+    /// ```
+    #[inline]
+    pub const fn concat(self, other: TinyStr8) -> TinyStr16 {
+        let word = self.as_unsigned().to_le();
+        let shift = 8 * w64::len(word);
+        let result = word as u128 | ((other.as_unsigned().to_le() as u128) << shift);
+        unsafe { TinyStr16::from_native_unchecked(u128::from_le(result)) }
+    }
+}
@@ -0,0 +1,152 @@
+use std::convert::TryFrom;
+
+use crate::swar::{w128, w32, w64};
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+#[cfg(any(feature = "std", test))]
+use std::{boxed::Box, string::String};
+
+#[cfg(all(not(feature = "std"), not(test), feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), not(test), feature = "alloc"))]
+use alloc::{boxed::Box, string::String};
+
+macro_rules! impl_std_conversions {
+    ($ty:ident) => {
+        impl TryFrom<&str> for $ty {
+            type Error = Error;
+
+            #[inline(always)]
+            fn try_from(text: &str) -> Result<Self, Self::Error> {
+                Self::from_bytes(text.as_bytes())
+            }
+        }
+
+        impl TryFrom<&[u8]> for $ty {
+            type Error = Error;
+
+            #[inline(always)]
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                Self::from_bytes(bytes)
+            }
+        }
+
+        impl PartialEq<str> for $ty {
+            #[inline(always)]
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<$ty> for str {
+            #[inline(always)]
+            fn eq(&self, other: &$ty) -> bool {
+                self == other.as_str()
+            }
+        }
+
+        impl PartialEq<$ty> for &str {
+            #[inline(always)]
+            fn eq(&self, other: &$ty) -> bool {
+                *self == other.as_str()
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl TryFrom<String> for $ty {
+            type Error = Error;
+
+            #[inline(always)]
+            fn try_from(text: String) -> Result<Self, Self::Error> {
+                Self::from_bytes(text.as_bytes())
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl From<$ty> for String {
+            #[inline]
+            fn from(tiny: $ty) -> Self {
+                tiny.as_str().into()
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl From<$ty> for Box<str> {
+            #[inline]
+            fn from(tiny: $ty) -> Self {
+                tiny.as_str().into()
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl PartialEq<String> for $ty {
+            #[inline(always)]
+            fn eq(&self, other: &String) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl PartialEq<$ty> for String {
+            #[inline(always)]
+            fn eq(&self, other: &$ty) -> bool {
+                self == other.as_str()
+            }
+        }
+    };
+}
+
+impl_std_conversions!(TinyStr4);
+impl_std_conversions!(TinyStr8);
+impl_std_conversions!(TinyStr16);
+
+macro_rules! impl_width_conversions {
+    ($narrow:ident, $narrow_int:ident, $narrow_swar:ident, $wide:ident, $wide_int:ident, $wide_swar:ident) => {
+        /// Zero-extends the integer, since both types are NUL-padded.
+        impl From<$narrow> for $wide {
+            #[inline(always)]
+            fn from(tiny: $narrow) -> Self {
+                // SAFETY: Zero-extending a valid word only adds NUL padding.
+                unsafe { Self::from_le_word_unchecked(tiny.as_unsigned().to_le() as $wide_int) }
+            }
+        }
+
+        /// Returns `Error::TooLong` if the string does not fit.
+        impl TryFrom<$wide> for $narrow {
+            type Error = Error;
+
+            #[inline]
+            fn try_from(tiny: $wide) -> Result<Self, Self::Error> {
+                let word = tiny.as_unsigned().to_le();
+                if word > <$narrow_int>::MAX as $wide_int {
+                    return Err(Error::TooLong {
+                        len: $wide_swar::len(word),
+                        max: $narrow_swar::LEN,
+                    });
+                }
+                // SAFETY: The bytes that are cut off are all NUL padding, so the narrow word
+                // is the same valid string.
+                Ok(unsafe { Self::from_le_word_unchecked(word as $narrow_int) })
+            }
+        }
+
+        impl PartialEq<$wide> for $narrow {
+            #[inline(always)]
+            fn eq(&self, other: &$wide) -> bool {
+                $wide::from(*self) == *other
+            }
+        }
+
+        impl PartialEq<$narrow> for $wide {
+            #[inline(always)]
+            fn eq(&self, other: &$narrow) -> bool {
+                *self == $wide::from(*other)
+            }
+        }
+    };
+}
+
+impl_width_conversions!(TinyStr4, u32, w32, TinyStr8, u64, w64);
+impl_width_conversions!(TinyStr4, u32, w32, TinyStr16, u128, w128);
+impl_width_conversions!(TinyStr8, u64, w64, TinyStr16, u128, w128);
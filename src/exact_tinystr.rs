@@ -0,0 +1,213 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::mutation::check_byte;
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+/// A tiny string that is exactly `N` ASCII characters, with `N` from 1 to 16.
+///
+/// Fixed-width codes, such as ISO 3166 alpha-3 regions, ISO 4217 currencies or FourCCs,
+/// are always the same length. `ExactTinyStr` rejects any other length with
+/// `Error::LengthMismatch`, and since the length is known, it stores no padding and
+/// dereferences to `&str` without computing it.
+///
+/// # Examples
+///
+/// ```
+/// use tinystr::{Error, ExactTinyStr};
+///
+/// let s1: ExactTinyStr<3> = "USD".parse()
+///     .expect("Failed to parse.");
+///
+/// assert_eq!(s1, "USD");
+/// assert_eq!(s1.len(), 3);
+/// assert_eq!(
+///     "US".parse::<ExactTinyStr<3>>(),
+///     Err(Error::LengthMismatch { len: 2, expected: 3 })
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ExactTinyStr<const N: usize> {
+    bytes: [u8; N],
+}
+
+impl<const N: usize> ExactTinyStr<N> {
+    const CAPACITY_CHECK: () =
+        assert!(N != 0 && N <= 16, "ExactTinyStr<N> requires N from 1 to 16");
+
+    /// Creates an `ExactTinyStr` from a byte slice of exactly `N` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::{Error, ExactTinyStr};
+    ///
+    /// let s1 = ExactTinyStr::<4>::from_bytes(b"avc1")
+    ///     .expect("Failed to parse.");
+    ///
+    /// assert_eq!(s1, "avc1");
+    /// assert_eq!(
+    ///     ExactTinyStr::<4>::from_bytes(b"av01!"),
+    ///     Err(Error::LengthMismatch { len: 5, expected: 4 })
+    /// );
+    /// assert_eq!(
+    ///     ExactTinyStr::<4>::from_bytes(b"av\01"),
+    ///     Err(Error::InteriorNull { index: 2 })
+    /// );
+    /// ```
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::CAPACITY_CHECK;
+        if bytes.len() != N {
+            return Err(Error::LengthMismatch {
+                len: bytes.len(),
+                expected: N,
+            });
+        }
+        let mut result = Self { bytes: [0; N] };
+        let mut i = 0;
+        while i < N {
+            if let Err(e) = check_byte(bytes[i], i) {
+                return Err(e);
+            }
+            result.bytes[i] = bytes[i];
+            i += 1;
+        }
+        Ok(result)
+    }
+
+    /// Creates an `ExactTinyStr` from an array of `N` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::ExactTinyStr;
+    ///
+    /// const EUR: ExactTinyStr<3> = match ExactTinyStr::from_array(*b"EUR") {
+    ///     Ok(s) => s,
+    ///     Err(_) => panic!(),
+    /// };
+    ///
+    /// assert_eq!(EUR, "EUR");
+    /// ```
+    #[inline]
+    pub const fn from_array(bytes: [u8; N]) -> Result<Self, Error> {
+        Self::from_bytes(&bytes)
+    }
+
+    /// Returns the length of the string, which is always `N`.
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns the bytes of the string.
+    #[inline(always)]
+    pub const fn as_array(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Extracts a string slice containing the entire `ExactTinyStr`.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.deref()
+    }
+}
+
+impl<const N: usize> fmt::Display for ExactTinyStr<N> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for ExactTinyStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.deref())
+    }
+}
+
+impl<const N: usize> Deref for ExactTinyStr<N> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        // The bytes are checked to be ASCII on construction.
+        unsafe { std::str::from_utf8_unchecked(&self.bytes) }
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for ExactTinyStr<N> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.deref() == *other
+    }
+}
+
+impl<const N: usize> FromStr for ExactTinyStr<N> {
+    type Err = Error;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(text.as_bytes())
+    }
+}
+
+macro_rules! impl_exact_conversions {
+    ($tiny:ident, $int:ident, $($size:literal),+) => {
+        $(
+            impl From<ExactTinyStr<$size>> for $tiny {
+                #[inline(always)]
+                fn from(exact: ExactTinyStr<$size>) -> Self {
+                    // The bytes are ASCII and non-NUL, and there are at most as many as fit.
+                    let mut bytes = [0; core::mem::size_of::<$int>()];
+                    bytes[..$size].copy_from_slice(&exact.bytes);
+                    unsafe { $tiny::from_native_unchecked($int::from_ne_bytes(bytes)) }
+                }
+            }
+        )+
+
+        impl<const N: usize> TryFrom<$tiny> for ExactTinyStr<N> {
+            type Error = Error;
+
+            #[inline(always)]
+            fn try_from(tiny: $tiny) -> Result<Self, Self::Error> {
+                Self::from_bytes(tiny.as_bytes())
+            }
+        }
+    };
+}
+
+impl_exact_conversions!(TinyStr4, u32, 1, 2, 3, 4);
+impl_exact_conversions!(TinyStr8, u64, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_exact_conversions!(TinyStr16, u128, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for ExactTinyStr<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for ExactTinyStr<N> {
+    fn deserialize<D>(deserializer: D) -> Result<ExactTinyStr<N>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use alloc::borrow::Cow;
+        use alloc::string::ToString;
+        use serde::de::Error as SerdeError;
+
+        let x: Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        x.parse()
+            .map_err(|e: Error| SerdeError::custom(e.to_string()))
+    }
+}
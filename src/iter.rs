@@ -0,0 +1,211 @@
+use core::iter::FusedIterator;
+
+use crate::swar::{w128, w32, w64};
+use crate::{TinyStr16, TinyStr4, TinyStr8};
+
+/// An iterator over the bytes of a TinyStr.
+///
+/// This is created by the `bytes` method on [`TinyStr4`], [`TinyStr8`] and [`TinyStr16`].
+/// The bytes are held in an integer of type `W`, the `u32`, `u64` or `u128` of the TinyStr,
+/// and shifted out as the iterator advances.
+#[derive(Copy, Clone, Debug)]
+pub struct Bytes<W> {
+    // Little-endian, NUL-padded word; the next byte is in the least significant byte.
+    word: W,
+    len: usize,
+}
+
+/// An iterator over the characters of a TinyStr.
+///
+/// This is created by the `chars` method on [`TinyStr4`], [`TinyStr8`] and [`TinyStr16`].
+/// Since TinyStrs are ASCII, each byte is one character.
+#[derive(Copy, Clone, Debug)]
+pub struct Chars<W>(Bytes<W>);
+
+macro_rules! impl_bytes_iter {
+    ($int:ident, $swar:ident) => {
+        impl Iterator for Bytes<$int> {
+            type Item = u8;
+
+            #[inline]
+            fn next(&mut self) -> Option<u8> {
+                if self.len == 0 {
+                    return None;
+                }
+                let byte = self.word as u8;
+                self.word >>= 8;
+                self.len -= 1;
+                Some(byte)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+
+            #[inline]
+            fn count(self) -> usize {
+                self.len
+            }
+
+            #[inline]
+            fn last(mut self) -> Option<u8> {
+                self.next_back()
+            }
+        }
+
+        impl DoubleEndedIterator for Bytes<$int> {
+            #[inline]
+            fn next_back(&mut self) -> Option<u8> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.len -= 1;
+                let byte = $swar::byte(self.word, self.len);
+                self.word &= $swar::low_bytes(self.len);
+                Some(byte)
+            }
+        }
+
+        impl ExactSizeIterator for Bytes<$int> {}
+
+        impl FusedIterator for Bytes<$int> {}
+
+        impl Iterator for Chars<$int> {
+            type Item = char;
+
+            #[inline]
+            fn next(&mut self) -> Option<char> {
+                self.0.next().map(char::from)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+
+            #[inline]
+            fn count(self) -> usize {
+                self.0.count()
+            }
+
+            #[inline]
+            fn last(self) -> Option<char> {
+                self.0.last().map(char::from)
+            }
+        }
+
+        impl DoubleEndedIterator for Chars<$int> {
+            #[inline]
+            fn next_back(&mut self) -> Option<char> {
+                self.0.next_back().map(char::from)
+            }
+        }
+
+        impl ExactSizeIterator for Chars<$int> {}
+
+        impl FusedIterator for Chars<$int> {}
+    };
+}
+
+impl_bytes_iter!(u32, w32);
+impl_bytes_iter!(u64, w64);
+impl_bytes_iter!(u128, w128);
+
+macro_rules! impl_iter {
+    ($ty:ident, $int:ident, $swar:ident, $example:literal) => {
+        impl $ty {
+            /// Returns the length of the string in bytes.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert_eq!(s1.len(), \"", $example, "\".len());")]
+            /// ```
+            #[inline(always)]
+            pub const fn len(&self) -> usize {
+                $swar::len(self.as_unsigned().to_le())
+            }
+
+            /// Returns `false`, since a TinyStr is never empty.
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                false
+            }
+
+            /// Returns the byte at `index`, or `None` if it is out of bounds.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.byte_at(0), Some(s1.as_bytes()[0]));
+            /// assert_eq!(s1.byte_at(s1.len()), None);
+            /// ```
+            #[inline]
+            pub const fn byte_at(&self, index: usize) -> Option<u8> {
+                let word = self.as_unsigned().to_le();
+                if index >= $swar::LEN {
+                    return None;
+                }
+                match $swar::byte(word, index) {
+                    0 => None,
+                    byte => Some(byte),
+                }
+            }
+
+            /// Returns a double-ended iterator over the bytes of the string.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.bytes().eq(s1.as_bytes().iter().copied()));
+            /// assert!(s1.bytes().rev().eq(s1.as_bytes().iter().rev().copied()));
+            /// ```
+            #[inline]
+            pub const fn bytes(&self) -> Bytes<$int> {
+                let word = self.as_unsigned().to_le();
+                Bytes {
+                    word,
+                    len: $swar::len(word),
+                }
+            }
+
+            /// Returns a double-ended iterator over the characters of the string.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.chars().eq(s1.as_str().chars()));
+            /// assert_eq!(s1.chars().next_back(), s1.as_str().chars().last());
+            /// ```
+            #[inline]
+            pub const fn chars(&self) -> Chars<$int> {
+                Chars(self.bytes())
+            }
+        }
+    };
+}
+
+impl_iter!(TinyStr4, u32, w32, "Test");
+impl_iter!(TinyStr8, u64, w64, "Testing");
+impl_iter!(TinyStr16, u128, w128, "Metamorphosis");
@@ -8,30 +8,43 @@
 //!
 //! # Details
 //!
-//! The crate provides three structs and an enum:
+//! The crate provides the following types:
 //! * `TinyStr4` an ASCII-only string limited to 4 characters.
 //! * `TinyStr8` an ASCII-only string limited to 8 characters.
 //! * `TinyStr16` an ASCII-only string limited to 16 characters.
+//! * `TinyStr4OrEmpty`, `TinyStr8OrEmpty` and `TinyStr16OrEmpty` which also allow the empty string.
+//! * `ExactTinyStr<N>` an ASCII-only string of exactly `N` characters, where `N` is 1 to 16.
+//! * `TinyUtf8Str<N>` a UTF-8 string limited to `N` bytes, where `N` is 4, 8 or 16.
+//! * `TinyBytes<N>` a byte string of arbitrary bytes limited to `N` bytes.
+//! * `TinyStrBuilder<N>` a buffer implementing `fmt::Write` for building TinyStrs with `write!`.
 //! * `TinyStrAuto` (enum):
 //!   * `Tiny` when the string is 16 characters or less.
 //!   * `Heap` when the string is 17 or more characters.
+//! * `TinyStrAutoUtf8` (enum):
+//!   * `Tiny` when the string is ASCII and 16 characters or less.
+//!   * `Heap` for any other UTF-8 string.
 //!
 //! `TinyStrAuto` stores the string as a TinyStr16 when it is short enough, or else falls back to a
 //! standard `String`. You should use TinyStrAuto when you expect most strings to be 16 characters
 //! or smaller, but occasionally you receive one that exceeds that length. Unlike the structs,
 //! `TinyStrAuto` does not implement `Copy`.
 //!
+//! `TinyStrAutoUtf8` works the same way, but accepts any UTF-8 input, including empty and
+//! non-ASCII strings, which are stored on the heap.
+//!
 //! # Macros
 //!
 //! Compile-time macros are available to convert string literals into const TinyStrs:
 //! * `tinystr4!("abc")`
 //! * `tinystr8!("abcdefg")`
 //! * `tinystr16!("longer-string")`
+//! * `exact_tinystr!("USD")`
 //!
 //! # no_std
 //!
-//! Disable the `std` feature of this crate to make it `#[no_std]`. Doing so disables `TinyStrAuto`.
-//! You can re-enable `TinyStrAuto` in `#[no_std]` mode by enabling the `alloc` feature.
+//! Disable the `std` feature of this crate to make it `#[no_std]`. Doing so disables `TinyStrAuto`
+//! and `TinyStrAutoUtf8`. You can re-enable them in `#[no_std]` mode by enabling the `alloc`
+//! feature.
 //!
 //! # Example
 //!
@@ -87,9 +100,30 @@ extern crate alloc;
 
 #[macro_use]
 mod macros;
+mod builder;
+mod case_insensitive;
+mod concat;
+mod conversions;
+mod exact_tinystr;
+mod iter;
+mod lossy;
+mod mutation;
+mod normalize;
+mod pattern;
+mod predicates;
+mod replace;
+mod search;
+mod slicing;
+mod sort_key;
+mod split;
+mod swar;
+mod tinybytes;
 mod tinystr16;
 mod tinystr4;
 mod tinystr8;
+mod tinystr_or_empty;
+mod tinyutf8str;
+mod unsigned;
 
 #[cfg(feature = "zerovec")]
 pub mod ule;
@@ -97,14 +131,37 @@ pub mod ule;
 /// Re-export of the low-level tinystr_macros crate, required by the macros.
 pub use tinystr_macros as raw_macros;
 
+/// Re-export of `serde`, required by `define_tinystr_type!`.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+
+/// Re-export of `zerovec`, required by `define_tinystr_type!`.
+#[cfg(feature = "zerovec")]
+#[doc(hidden)]
+pub use zerovec;
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 mod tinystrauto;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod tinystrautoutf8;
 
+pub use builder::TinyStrBuilder;
+pub use case_insensitive::AsciiCaseInsensitive;
+pub use exact_tinystr::ExactTinyStr;
+pub use iter::{Bytes, Chars};
+pub use pattern::{TinyPattern, TinyShape};
+pub use split::SplitByte;
+pub use tinybytes::TinyBytes;
 pub use tinystr16::TinyStr16;
 pub use tinystr4::TinyStr4;
 pub use tinystr8::TinyStr8;
+pub use tinystr_or_empty::{TinyStr16OrEmpty, TinyStr4OrEmpty, TinyStr8OrEmpty};
+pub use tinyutf8str::{TinyUtf8Str, Utf8Capacity, Utf8Storage};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use tinystrauto::TinyStrAuto;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use tinystrautoutf8::TinyStrAutoUtf8;
 
-pub use tinystr_raw::Error;
+pub use tinystr_raw::{AsciiCase, AsciiClass, Error};
@@ -0,0 +1,158 @@
+use crate::mutation::check_byte;
+use crate::swar::{w128, w32, w64};
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+/// The ASCII base letters of U+00C0 to U+00FF, with NUL for the characters that have
+/// none or map to more than one letter.
+const LATIN_1: &[u8; 64] =
+    b"AAAAAA\0CEEEEIIIIDNOOOOO\0OUUUUY\0\0aaaaaa\0ceeeeiiiidnooooo\0ouuuuy\0y";
+
+/// The ASCII base letters of U+0100 to U+017F, with NUL for the ligatures.
+const LATIN_EXTENDED_A: &[u8; 128] = b"AaAaAaCcCcCcCcDdDdEeEeEeEeEeGgGgGgGgHhHhIiIiIiIiIi\0\0JjKkkLlLlLlLlLlNnNnNnnNnOoOoOo\0\0RrRrRrSsSsSsSsTtTtTtUuUuUuUuUuUuWwYyYZzZzZzs";
+
+/// Returns the ASCII transliteration of a character, which is empty if there is none.
+fn transliterate(c: char) -> &'static [u8] {
+    let (table, index): (&'static [u8], usize) = match c {
+        'Æ' => return b"AE",
+        'æ' => return b"ae",
+        'Þ' => return b"TH",
+        'þ' => return b"th",
+        'ß' => return b"ss",
+        'Ĳ' => return b"IJ",
+        'ĳ' => return b"ij",
+        'Œ' => return b"OE",
+        'œ' => return b"oe",
+        '\u{c0}'..='\u{ff}' => (LATIN_1, c as usize - 0xc0),
+        '\u{100}'..='\u{17f}' => (LATIN_EXTENDED_A, c as usize - 0x100),
+        _ => return b"",
+    };
+    match table[index] {
+        0 => b"",
+        _ => &table[index..=index],
+    }
+}
+
+macro_rules! impl_lossy {
+    ($ty:ident, $int:ident, $swar:ident, $len:literal, $example:literal, $truncated:literal) => {
+        impl $ty {
+            #[doc = concat!("Creates a ", stringify!($ty), " from the first ", $len, " bytes of a byte slice, and returns")]
+            /// whether any bytes were dropped.
+            ///
+            /// Returns an error if the slice is empty, or if a kept byte is NUL or non-ASCII.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let (s1, truncated) = ", stringify!($ty), "::from_bytes_truncating(b\"", $example, "\")")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert_eq!(s1, \"", $truncated, "\");")]
+            /// assert!(truncated);
+            /// ```
+            #[inline]
+            pub fn from_bytes_truncating(bytes: &[u8]) -> Result<(Self, bool), Error> {
+                let truncated = bytes.len() > $swar::LEN;
+                let bytes = if truncated { &bytes[..$swar::LEN] } else { bytes };
+                Self::from_bytes(bytes).map(|s| (s, truncated))
+            }
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from a byte slice, replacing each NUL or non-ASCII byte")]
+            /// with `replacement`, and returns whether any bytes were replaced.
+            ///
+            /// Returns an error if the slice is empty or too long, or if `replacement` is
+            /// NUL or non-ASCII.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let (s1, replaced) = ", stringify!($ty), "::from_bytes_lossy(b\"a\\0\\xff\", b'?')")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1, "a??");
+            /// assert!(replaced);
+            /// ```
+            #[inline]
+            pub const fn from_bytes_lossy(bytes: &[u8], replacement: u8) -> Result<(Self, bool), Error> {
+                let word = match $swar::from_bytes(bytes) {
+                    Some(word) => word,
+                    None => {
+                        return Err(Error::TooLong {
+                            len: bytes.len(),
+                            max: $swar::LEN,
+                        })
+                    }
+                };
+                if bytes.is_empty() {
+                    return Err(Error::Empty);
+                }
+                let content = $swar::HIGH & $swar::low_bytes(bytes.len());
+                let mask = ((word & $swar::HIGH) | $swar::eq_mask(word, 0)) & content;
+                // The index is that of the first replaced byte, if any.
+                let index = (mask.trailing_zeros() / 8) as usize % $swar::LEN;
+                if let Err(e) = check_byte(replacement, index) {
+                    return Err(e);
+                }
+                let mask = $swar::expand(mask);
+                let result = (word & !mask) | ($swar::ONES * replacement as $int & mask);
+                // SAFETY: `bytes` is not empty, and every NUL or non-ASCII byte in it is
+                // replaced with `replacement`, which is checked to be ASCII and not NUL.
+                Ok((unsafe { Self::from_le_word_unchecked(result) }, mask != 0))
+            }
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from a string, mapping the letters of Latin-1 and Latin")]
+            /// Extended-A to their ASCII base letters and truncating the result to fit, and
+            /// returns whether the result differs from `text`.
+            ///
+            /// For example, `é` becomes `e` and `ß` becomes `ss`. NUL and other non-ASCII
+            /// characters are dropped, and truncation never splits a mapped character.
+            /// Returns `Error::Empty` if nothing is left.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let (s1, lossy) = ", stringify!($ty), "::from_str_transliterated(\"Fuß\")")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1, "Fuss");
+            /// assert!(lossy);
+            /// ```
+            pub fn from_str_transliterated(text: &str) -> Result<(Self, bool), Error> {
+                let mut bytes = [0; $swar::LEN];
+                let mut len = 0;
+                let mut lossy = false;
+                for (i, c) in text.char_indices() {
+                    let mapped = match c {
+                        '\0' => &[][..],
+                        _ if c.is_ascii() => &text.as_bytes()[i..=i],
+                        _ => transliterate(c),
+                    };
+                    lossy |= c == '\0' || !c.is_ascii();
+                    if len + mapped.len() > $swar::LEN {
+                        lossy = true;
+                        break;
+                    }
+                    bytes[len..len + mapped.len()].copy_from_slice(mapped);
+                    len += mapped.len();
+                }
+                Self::from_bytes(&bytes[..len]).map(|s| (s, lossy))
+            }
+        }
+    };
+}
+
+impl_lossy!(TinyStr4, u32, w32, 4, "Tests", "Test");
+impl_lossy!(TinyStr8, u64, w64, 8, "Testing123", "Testing1");
+impl_lossy!(
+    TinyStr16,
+    u128,
+    w128,
+    16,
+    "Metamorphosis1234",
+    "Metamorphosis123"
+);
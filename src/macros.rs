@@ -77,6 +77,311 @@ macro_rules! tinystr16 {
     };
 }
 
+/// Macro to create a const ExactTinyStr, whose length is the length of the literal.
+///
+/// The argument must be a string literal:
+/// https://doc.rust-lang.org/reference/tokens.html#string-literals
+///
+/// # Example
+///
+/// ```
+/// use tinystr::{exact_tinystr, ExactTinyStr};
+///
+/// const S1: ExactTinyStr<3> = exact_tinystr!("USD");
+/// let s2: ExactTinyStr<3> = "USD".parse().unwrap();
+/// assert_eq!(S1, s2);
+/// ```
+#[macro_export]
+macro_rules! exact_tinystr {
+    ($s:literal) => {{
+        const BYTES: &[u8] = $s.as_bytes();
+        const VALUE: $crate::ExactTinyStr<{ BYTES.len() }> =
+            match $crate::ExactTinyStr::from_bytes(BYTES) {
+                ::core::result::Result::Ok(value) => value,
+                ::core::result::Result::Err(_) => panic!(concat!("Invalid ExactTinyStr: ", $s)),
+            };
+        VALUE
+    }};
+}
+
+#[test]
+fn test_exact_tinystr() {
+    use crate::ExactTinyStr;
+    const X1: ExactTinyStr<4> = exact_tinystr!("mp4a");
+    let x2: ExactTinyStr<4> = "mp4a".parse().unwrap();
+    assert_eq!(X1, x2);
+}
+
+/// Macro to define a validated, normalizing newtype around a TinyStr.
+///
+/// The newtype wraps a `TinyStr4`, `TinyStr8` or `TinyStr16`. Every value is normalized to
+/// `lowercase`, `uppercase` or `titlecase` (or left as is with `none`), and then checked
+/// with either a `validate` predicate, a `const fn` taking the TinyStr and returning `bool`,
+/// or a [`TinyPattern`](crate::TinyPattern). Values failing the check are rejected with
+/// `Error::InvalidFormat`.
+///
+/// The newtype implements `Copy`, `Eq`, `Ord`, `Hash`, `Display`, `Debug`, `Deref<Target = str>`,
+/// `FromStr`, `TryFrom` the TinyStr and `From` the newtype for the TinyStr, as well as the
+/// `serde` and `zerovec` traits when the respective features of `tinystr` are enabled.
+/// Byte slices parsed with `zerovec` are validated too, and must hold normalized values.
+///
+/// A macro is also defined under the `literal` name, to create a const value from a string
+/// literal; it fails to compile if the literal is not valid.
+///
+/// # Example
+///
+/// ```
+/// use tinystr::{define_tinystr_type, AsciiClass, Error, TinyPattern, TinyShape};
+///
+/// const LANGUAGE: TinyPattern = TinyPattern::new(TinyShape::new(2, 3).all(AsciiClass::ALPHABETIC))
+///     .or(TinyShape::new(5, 8).all(AsciiClass::ALPHABETIC));
+///
+/// define_tinystr_type! {
+///     /// A language subtag, such as "en" or "zh".
+///     pub struct Language(TinyStr8);
+///     pattern: LANGUAGE;
+///     normalize: lowercase;
+///     literal: language;
+/// }
+///
+/// const EN: Language = language!("EN");
+///
+/// let lang: Language = "En".parse().expect("Failed to parse.");
+/// assert_eq!(lang, EN);
+/// assert_eq!(lang, "en");
+/// assert_eq!("e".parse::<Language>(), Err(Error::InvalidFormat));
+/// ```
+#[macro_export]
+macro_rules! define_tinystr_type {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($base:ident);
+        validate: $check:path;
+        normalize: $mode:ident;
+        literal: $literal:ident;
+    ) => {
+        $crate::define_tinystr_type!(
+            @define ($) [$(#[$attr])*] $vis $name $base [validate $check] $mode $literal
+        );
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($base:ident);
+        pattern: $check:path;
+        normalize: $mode:ident;
+        literal: $literal:ident;
+    ) => {
+        $crate::define_tinystr_type!(
+            @define ($) [$(#[$attr])*] $vis $name $base [pattern $check] $mode $literal
+        );
+    };
+    (
+        @define ($d:tt) [$($attr:tt)*] $vis:vis $name:ident $base:ident
+        [$kind:ident $check:path] $mode:ident $literal:ident
+    ) => {
+        $($attr)*
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(transparent)]
+        $vis struct $name($crate::$base);
+
+        impl $name {
+            #[doc = concat!("Normalizes and validates a [`", stringify!($base), "`].")]
+            pub const fn try_from_tinystr(
+                tiny: $crate::$base,
+            ) -> ::core::result::Result<Self, $crate::Error> {
+                let tiny = $crate::define_tinystr_type!(@normalize $mode, tiny);
+                if $crate::define_tinystr_type!(@check $kind $check, tiny) {
+                    ::core::result::Result::Ok(Self(tiny))
+                } else {
+                    ::core::result::Result::Err($crate::Error::InvalidFormat)
+                }
+            }
+
+            #[doc = concat!("Creates a ", stringify!($name), " from a byte slice, normalizing and validating it.")]
+            pub fn from_bytes(bytes: &[u8]) -> ::core::result::Result<Self, $crate::Error> {
+                Self::try_from_tinystr($crate::$base::from_bytes(bytes)?)
+            }
+
+            #[doc = concat!("Returns the underlying [`", stringify!($base), "`].")]
+            pub const fn into_tinystr(self) -> $crate::$base {
+                self.0
+            }
+
+            #[doc = concat!("Extracts a string slice containing the entire `", stringify!($name), "`.")]
+            pub fn as_str(&self) -> &str {
+                self.0.as_str()
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::cmp::PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::Error;
+
+            fn from_str(text: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::from_bytes(text.as_bytes())
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::$base> for $name {
+            type Error = $crate::Error;
+
+            fn try_from(tiny: $crate::$base) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_from_tinystr(tiny)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::$base {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        $crate::__tinystr_type_serde_impl!($name);
+        $crate::__tinystr_type_ule_impl!($name, $base);
+
+        #[allow(unused_macros)]
+        macro_rules! $literal {
+            ($d s:literal) => {{
+                const VALUE: $name = match $name::try_from_tinystr(
+                    $crate::define_tinystr_type!(@literal $base, $d s)
+                ) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => {
+                        panic!(concat!("Invalid ", stringify!($name), ": ", $d s))
+                    }
+                };
+                VALUE
+            }};
+        }
+    };
+    (@normalize none, $tiny:expr) => { $tiny };
+    (@normalize lowercase, $tiny:expr) => { $tiny.to_ascii_lowercase() };
+    (@normalize uppercase, $tiny:expr) => { $tiny.to_ascii_uppercase() };
+    (@normalize titlecase, $tiny:expr) => { $tiny.to_ascii_titlecase() };
+    (@check validate $check:path, $tiny:expr) => { $check($tiny) };
+    (@check pattern $check:path, $tiny:expr) => { $tiny.matches_pattern(&$check) };
+    (@literal TinyStr4, $s:literal) => { $crate::tinystr4!($s) };
+    (@literal TinyStr8, $s:literal) => { $crate::tinystr8!($s) };
+    (@literal TinyStr16, $s:literal) => { $crate::tinystr16!($s) };
+}
+
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! __tinystr_type_serde_impl {
+    ($name:ident) => {
+        impl $crate::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de> $crate::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<$name, D::Error>
+            where
+                D: $crate::serde::Deserializer<'de>,
+            {
+                let tiny = $crate::serde::Deserialize::deserialize(deserializer)?;
+                Self::try_from_tinystr(tiny).map_err(|_| {
+                    <D::Error as $crate::serde::de::Error>::custom(concat!(
+                        "invalid ",
+                        stringify!($name)
+                    ))
+                })
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! __tinystr_type_serde_impl {
+    ($name:ident) => {};
+}
+
+#[doc(hidden)]
+#[cfg(feature = "zerovec")]
+#[macro_export]
+macro_rules! __tinystr_type_ule_impl {
+    ($name:ident, $base:ident) => {
+        const _: () = {
+            use $crate::zerovec::ule::{AsULE, ULE};
+
+            type BaseULE = <$crate::$base as AsULE>::ULE;
+
+            #[doc = concat!("The unaligned version of [`", stringify!($name), "`], which only accepts normalized values.")]
+            #[repr(transparent)]
+            #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+            pub struct TinyStrTypeULE(BaseULE);
+
+            unsafe impl ULE for TinyStrTypeULE {
+                type Error = $crate::Error;
+
+                fn validate_byte_slice(bytes: &[u8]) -> ::core::result::Result<(), Self::Error> {
+                    for unaligned in BaseULE::parse_byte_slice(bytes)? {
+                        let tiny = <$crate::$base as AsULE>::from_unaligned(*unaligned);
+                        if $name::try_from_tinystr(tiny)?.0 != tiny {
+                            return ::core::result::Result::Err($crate::Error::InvalidFormat);
+                        }
+                    }
+                    ::core::result::Result::Ok(())
+                }
+            }
+
+            impl AsULE for $name {
+                type ULE = TinyStrTypeULE;
+
+                #[inline]
+                fn as_unaligned(self) -> Self::ULE {
+                    TinyStrTypeULE(AsULE::as_unaligned(self.0))
+                }
+
+                #[inline]
+                fn from_unaligned(unaligned: Self::ULE) -> Self {
+                    // Values are normalized and validated by `validate_byte_slice`.
+                    Self(AsULE::from_unaligned(unaligned.0))
+                }
+            }
+        };
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "zerovec"))]
+#[macro_export]
+macro_rules! __tinystr_type_ule_impl {
+    ($name:ident, $base:ident) => {};
+}
+
 // Internal macro for implementing Serialize/Deserialize
 macro_rules! serde_impl {
     ($ty:ident, $int:ident) => {
@@ -100,9 +405,9 @@ macro_rules! serde_impl {
             where
                 D: serde::Deserializer<'de>,
             {
-                use serde::de::Error as SerdeError;
                 use alloc::borrow::Cow;
                 use alloc::string::ToString;
+                use serde::de::Error as SerdeError;
 
                 if deserializer.is_human_readable() {
                     let x: Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
@@ -111,11 +416,8 @@ macro_rules! serde_impl {
                 } else {
                     // little-endian
                     let le = serde::Deserialize::deserialize(deserializer)?;
-                    let bytes = $int::from_le(le).to_ne_bytes();
-                    let bytes = bytes.split(|t| *t == 0).next().ok_or_else(|| {
-                        SerdeError::custom(concat!("Empty string found for ", stringify!($ty)))
-                    })?;
-                    <$ty>::from_bytes(&bytes).map_err(|e| SerdeError::custom(e.to_string()))
+                    <$ty>::from_padded_bytes(&$int::from_le(le).to_ne_bytes())
+                        .map_err(|e| SerdeError::custom(e.to_string()))
                 }
             }
         }
@@ -0,0 +1,331 @@
+use crate::swar::{w128, w32, w64};
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+/// Checks that `byte` can be stored in a TinyStr, at `index` for the error.
+#[inline(always)]
+pub(crate) const fn check_byte(byte: u8, index: usize) -> Result<(), Error> {
+    if byte == 0 {
+        Err(Error::InteriorNull { index })
+    } else if byte >= 0x80 {
+        Err(Error::NonAscii { index, byte })
+    } else {
+        Ok(())
+    }
+}
+
+macro_rules! impl_mutation {
+    ($ty:ident, $int:ident, $swar:ident, $example:literal, $push_index:literal) => {
+        impl $ty {
+            /// Appends a byte to the end of the string.
+            ///
+            /// Returns `Error::TooLong` if the string is already full, or an error if
+            /// `byte` is NUL or non-ASCII. The string is unchanged on error.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let mut s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.try_push(b'!'), Ok(()));
+            #[doc = concat!("assert_eq!(s1, \"", $example, "!\");")]
+            #[doc = concat!("assert_eq!(s1.try_push(0), Err(Error::InteriorNull { index: ", $push_index, " }));")]
+            /// ```
+            #[inline]
+            pub fn try_push(&mut self, byte: u8) -> Result<(), Error> {
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                check_byte(byte, len)?;
+                if len == $swar::LEN {
+                    return Err(Error::TooLong {
+                        len: len + 1,
+                        max: $swar::LEN,
+                    });
+                }
+                // SAFETY: `byte` is checked to be ASCII and not NUL, and is stored right
+                // after the last byte, which is not past the end.
+                *self = unsafe { Self::from_le_word_unchecked(word | (byte as $int) << (8 * len)) };
+                Ok(())
+            }
+
+            /// Removes the last byte and returns it.
+            ///
+            /// Returns `Error::Empty` if this would leave the string empty.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let mut s1: ", stringify!($ty), " = \"ab\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.pop(), Ok(b'b'));
+            /// assert_eq!(s1, "a");
+            /// assert_eq!(s1.pop(), Err(Error::Empty));
+            /// ```
+            #[inline]
+            pub fn pop(&mut self) -> Result<u8, Error> {
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                if len == 1 {
+                    return Err(Error::Empty);
+                }
+                // SAFETY: `len > 1`, so the first byte is kept, and masking off the last byte
+                // keeps the rest ASCII and NUL-padded.
+                *self = unsafe { Self::from_le_word_unchecked(word & $swar::low_bytes(len - 1)) };
+                Ok($swar::byte(word, len - 1))
+            }
+
+            /// Shortens the string to its first `len` bytes.
+            ///
+            /// Does nothing if `len` is not less than the current length. Returns
+            /// `Error::Empty` if `len` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let mut s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.truncate(2), Ok(()));
+            #[doc = concat!("assert_eq!(s1, &\"", $example, "\"[..2]);")]
+            /// assert_eq!(s1.truncate(0), Err(Error::Empty));
+            /// ```
+            #[inline]
+            pub fn truncate(&mut self, len: usize) -> Result<(), Error> {
+                if len == 0 {
+                    return Err(Error::Empty);
+                }
+                let word = self.as_unsigned().to_le();
+                // SAFETY: `len > 0`, so the first byte is kept, and masking off the last bytes
+                // keeps the rest ASCII and NUL-padded.
+                *self = unsafe { Self::from_le_word_unchecked(word & $swar::low_bytes(len)) };
+                Ok(())
+            }
+
+            /// Replaces the byte at `index` with `byte`.
+            ///
+            /// Returns `Error::IndexOutOfBounds` if `index` is out of bounds, or an error if
+            /// `byte` is NUL or non-ASCII. The string is unchanged on error.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let mut s1: ", stringify!($ty), " = \"abc\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.try_set(1, b'-'), Ok(()));
+            /// assert_eq!(s1, "a-c");
+            /// assert_eq!(s1.try_set(3, b'-'), Err(Error::IndexOutOfBounds { index: 3, len: 3 }));
+            /// assert_eq!(s1.try_set(0, 0), Err(Error::InteriorNull { index: 0 }));
+            /// ```
+            #[inline]
+            pub fn try_set(&mut self, index: usize, byte: u8) -> Result<(), Error> {
+                check_byte(byte, index)?;
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                if index >= len {
+                    return Err(Error::IndexOutOfBounds { index, len });
+                }
+                let shift = 8 * index;
+                // SAFETY: `byte` is checked to be ASCII and not NUL, and replaces a content
+                // byte since `index < len`.
+                *self = unsafe {
+                    Self::from_le_word_unchecked(word & !(0xff << shift) | (byte as $int) << shift)
+                };
+                Ok(())
+            }
+
+            /// Inserts a byte at `index`, shifting the following bytes towards the end.
+            ///
+            /// Returns `Error::IndexOutOfBounds` if `index` is past the end of the string,
+            /// `Error::TooLong` if the string is already full, or an error if `byte` is
+            /// NUL or non-ASCII. The string is unchanged on error.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let mut s1: ", stringify!($ty), " = \"ac\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.try_insert(1, b'b'), Ok(()));
+            /// assert_eq!(s1, "abc");
+            /// assert_eq!(s1.try_insert(4, b'd'), Err(Error::IndexOutOfBounds { index: 4, len: 3 }));
+            /// ```
+            #[inline]
+            pub fn try_insert(&mut self, index: usize, byte: u8) -> Result<(), Error> {
+                check_byte(byte, index)?;
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                if index > len {
+                    return Err(Error::IndexOutOfBounds { index, len });
+                }
+                if len == $swar::LEN {
+                    return Err(Error::TooLong {
+                        len: len + 1,
+                        max: $swar::LEN,
+                    });
+                }
+                let head = word & $swar::low_bytes(index);
+                let tail = word & !$swar::low_bytes(index);
+                // SAFETY: `byte` is checked to be ASCII and not NUL, `index <= len` puts it
+                // next to a content byte, and the string is not full, so no byte is lost.
+                *self = unsafe {
+                    Self::from_le_word_unchecked(
+                        head | (byte as $int) << (8 * index) | $swar::shl_bytes(tail, 1),
+                    )
+                };
+                Ok(())
+            }
+
+            /// Removes the byte at `index` and returns it, shifting the following bytes
+            /// towards the start.
+            ///
+            /// Returns `Error::IndexOutOfBounds` if `index` is out of bounds, or
+            /// `Error::Empty` if this would leave the string empty.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let mut s1: ", stringify!($ty), " = \"abc\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.remove(1), Ok(b'b'));
+            /// assert_eq!(s1, "ac");
+            /// assert_eq!(s1.remove(2), Err(Error::IndexOutOfBounds { index: 2, len: 2 }));
+            /// ```
+            #[inline]
+            pub fn remove(&mut self, index: usize) -> Result<u8, Error> {
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                if index >= len {
+                    return Err(Error::IndexOutOfBounds { index, len });
+                }
+                if len == 1 {
+                    return Err(Error::Empty);
+                }
+                let head = word & $swar::low_bytes(index);
+                let tail = $swar::shr_bytes(word, index + 1);
+                // SAFETY: `len > 1`, so at least one content byte is left, and the bytes
+                // after `index` are moved down by one, which keeps them NUL-padded.
+                *self =
+                    unsafe { Self::from_le_word_unchecked(head | $swar::shl_bytes(tail, index)) };
+                Ok($swar::byte(word, index))
+            }
+
+            /// Pads the start of the string with `fill` until it is `width` bytes long.
+            ///
+            /// Does nothing if the string is already at least `width` bytes long. Returns
+            /// `Error::TooLong` if `width` is greater than the capacity, or an error if
+            /// `fill` is NUL or non-ASCII.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let mut s1: ", stringify!($ty), " = \"7\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// s1.pad_start(3, b'0').expect("Failed to pad.");
+            /// assert_eq!(s1, "007");
+            /// ```
+            #[inline]
+            pub fn pad_start(&mut self, width: usize, fill: u8) -> Result<(), Error> {
+                check_byte(fill, 0)?;
+                if width > $swar::LEN {
+                    return Err(Error::TooLong {
+                        len: width,
+                        max: $swar::LEN,
+                    });
+                }
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                if len < width {
+                    let pad = width - len;
+                    let fill = $swar::ONES * fill as $int & $swar::low_bytes(pad);
+                    // SAFETY: `fill` is checked to be ASCII and not NUL, and fills the `pad`
+                    // bytes freed at the start, while `width <= LEN` keeps every content byte.
+                    *self =
+                        unsafe { Self::from_le_word_unchecked($swar::shl_bytes(word, pad) | fill) };
+                }
+                Ok(())
+            }
+
+            /// Pads the end of the string with `fill` until it is `width` bytes long.
+            ///
+            /// Does nothing if the string is already at least `width` bytes long. Returns
+            /// `Error::TooLong` if `width` is greater than the capacity, or an error if
+            /// `fill` is NUL or non-ASCII.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let mut s1: ", stringify!($ty), " = \"ab\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// s1.pad_end(4, b'_').expect("Failed to pad.");
+            /// assert_eq!(s1, "ab__");
+            /// ```
+            #[inline]
+            pub fn pad_end(&mut self, width: usize, fill: u8) -> Result<(), Error> {
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                check_byte(fill, len)?;
+                if width > $swar::LEN {
+                    return Err(Error::TooLong {
+                        len: width,
+                        max: $swar::LEN,
+                    });
+                }
+                let fill =
+                    $swar::ONES * fill as $int & $swar::low_bytes(width) & !$swar::low_bytes(len);
+                // SAFETY: `fill` is checked to be ASCII and not NUL, and only fills the bytes
+                // from the end of the string to `width`.
+                *self = unsafe { Self::from_le_word_unchecked(word | fill) };
+                Ok(())
+            }
+
+            /// Converts this string to its ASCII lower case equivalent in-place.
+            ///
+            #[doc = concat!("See [`", stringify!($ty), "::to_ascii_lowercase`].")]
+            #[inline]
+            pub fn make_ascii_lowercase(&mut self) {
+                *self = self.to_ascii_lowercase();
+            }
+
+            /// Converts this string to its ASCII title case equivalent in-place.
+            ///
+            #[doc = concat!("See [`", stringify!($ty), "::to_ascii_titlecase`].")]
+            #[inline]
+            pub fn make_ascii_titlecase(&mut self) {
+                *self = self.to_ascii_titlecase();
+            }
+
+            /// Converts this string to its ASCII upper case equivalent in-place.
+            ///
+            #[doc = concat!("See [`", stringify!($ty), "::to_ascii_uppercase`].")]
+            #[inline]
+            pub fn make_ascii_uppercase(&mut self) {
+                *self = self.to_ascii_uppercase();
+            }
+        }
+    };
+}
+
+impl_mutation!(TinyStr4, u32, w32, "Tst", 4);
+impl_mutation!(TinyStr8, u64, w64, "Testing", 8);
+impl_mutation!(TinyStr16, u128, w128, "Metamorphosis", 14);
@@ -0,0 +1,140 @@
+use crate::{
+    AsciiCase, AsciiClass, Error, TinyStr16, TinyStr16OrEmpty, TinyStr4, TinyStr4OrEmpty, TinyStr8,
+    TinyStr8OrEmpty,
+};
+
+macro_rules! impl_normalize {
+    ($ty:ident, $or_empty:ident, $try_from_bytes_with:ident, $example:literal, $lower:literal) => {
+        impl $ty {
+            #[doc = concat!("Creates a ", stringify!($ty), " from a byte slice, checking that every byte is in")]
+            /// `class` and applying `case` in the same pass.
+            ///
+            /// Returns the same errors as `from_bytes`, or `Error::DisallowedCharacter` if a
+            /// byte is not in `class`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{AsciiCase, AsciiClass, Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1 = ", stringify!($ty), "::from_bytes_with(b\"a_b1\", AsciiClass::ALPHANUMERIC.union(AsciiClass::byte(b'_')), AsciiCase::Uppercase)")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1, "A_B1");
+            /// assert_eq!(
+            #[doc = concat!("    ", stringify!($ty), "::from_bytes_with(b\"a-b\", AsciiClass::ALPHABETIC, AsciiCase::Unchanged),")]
+            ///     Err(Error::DisallowedCharacter { index: 1, byte: b'-' })
+            /// );
+            /// ```
+            #[inline(always)]
+            pub fn from_bytes_with(
+                bytes: &[u8],
+                class: AsciiClass,
+                case: AsciiCase,
+            ) -> Result<Self, Error> {
+                tinystr_raw::$try_from_bytes_with(bytes, class, case)
+                    // The raw constructor returns a valid native-endian word.
+                    .map(|word| unsafe { Self::from_native_unchecked(word.get()) })
+            }
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from a byte slice, converted to ASCII lowercase.")]
+            ///
+            /// This is equivalent to, but faster than, `from_bytes` followed by
+            /// `to_ascii_lowercase`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1 = ", stringify!($ty), "::from_bytes_lowercase(b\"", $example, "\")")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert_eq!(s1, \"", $lower, "\");")]
+            /// ```
+            #[inline(always)]
+            pub fn from_bytes_lowercase(bytes: &[u8]) -> Result<Self, Error> {
+                Self::from_bytes_with(bytes, AsciiClass::ANY, AsciiCase::Lowercase)
+            }
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from a byte slice of ASCII letters, converted to")]
+            /// ASCII lowercase.
+            ///
+            /// This is equivalent to, but faster than, `from_bytes` followed by
+            /// `is_ascii_alphabetic` and `to_ascii_lowercase`. Returns
+            /// `Error::DisallowedCharacter` if a byte is not an ASCII letter.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1 = ", stringify!($ty), "::from_bytes_alpha_lowercase(b\"EnG\")")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1, "eng");
+            /// assert_eq!(
+            #[doc = concat!("    ", stringify!($ty), "::from_bytes_alpha_lowercase(b\"e1\"),")]
+            ///     Err(Error::DisallowedCharacter { index: 1, byte: b'1' })
+            /// );
+            /// ```
+            #[inline(always)]
+            pub fn from_bytes_alpha_lowercase(bytes: &[u8]) -> Result<Self, Error> {
+                Self::from_bytes_with(bytes, AsciiClass::ALPHABETIC, AsciiCase::Lowercase)
+            }
+        }
+
+        impl $or_empty {
+            #[doc = concat!("Creates a ", stringify!($or_empty), " from a byte slice, which may be empty, checking")]
+            /// that every byte is in `class` and applying `case` in the same pass.
+            #[doc = concat!("See [`", stringify!($ty), "::from_bytes_with`].")]
+            #[inline]
+            pub fn from_bytes_with(
+                bytes: &[u8],
+                class: AsciiClass,
+                case: AsciiCase,
+            ) -> Result<Self, Error> {
+                if bytes.is_empty() {
+                    return Ok(Self::EMPTY);
+                }
+                $ty::from_bytes_with(bytes, class, case).map(Self::from_tinystr)
+            }
+
+            #[doc = concat!("Creates a ", stringify!($or_empty), " from a byte slice, which may be empty,")]
+            /// converted to ASCII lowercase.
+            #[inline]
+            pub fn from_bytes_lowercase(bytes: &[u8]) -> Result<Self, Error> {
+                Self::from_bytes_with(bytes, AsciiClass::ANY, AsciiCase::Lowercase)
+            }
+
+            #[doc = concat!("Creates a ", stringify!($or_empty), " from a byte slice of ASCII letters, which may be")]
+            /// empty, converted to ASCII lowercase.
+            #[inline]
+            pub fn from_bytes_alpha_lowercase(bytes: &[u8]) -> Result<Self, Error> {
+                Self::from_bytes_with(bytes, AsciiClass::ALPHABETIC, AsciiCase::Lowercase)
+            }
+        }
+    };
+}
+
+impl_normalize!(
+    TinyStr4,
+    TinyStr4OrEmpty,
+    try_u32_from_bytes_with,
+    "TeSt",
+    "test"
+);
+impl_normalize!(
+    TinyStr8,
+    TinyStr8OrEmpty,
+    try_u64_from_bytes_with,
+    "TeSting",
+    "testing"
+);
+impl_normalize!(
+    TinyStr16,
+    TinyStr16OrEmpty,
+    try_u128_from_bytes_with,
+    "MetaMorphosis",
+    "metamorphosis"
+);
@@ -0,0 +1,193 @@
+use crate::swar::{w128, w32, w64};
+use crate::{AsciiClass, TinyStr16, TinyStr4, TinyStr8};
+
+/// The maximum number of rules in a [`TinyShape`].
+const MAX_RULES: usize = 4;
+/// The maximum number of shapes in a [`TinyPattern`].
+const MAX_SHAPES: usize = 4;
+
+/// A single shape of a [`TinyPattern`]: a length range and the character classes
+/// allowed at each position.
+///
+/// Positions without a rule accept any character. When several rules cover a
+/// position, the character has to be in all of their classes.
+///
+/// # Examples
+///
+/// ```
+/// use tinystr::{tinystr8, AsciiClass, TinyShape};
+///
+/// // A digit followed by 3 alphanumerics.
+/// const SHAPE: TinyShape = TinyShape::new(4, 4)
+///     .at(0, AsciiClass::DIGIT)
+///     .starting_at(1, AsciiClass::ALPHANUMERIC);
+///
+/// assert!(tinystr8!("1994").matches_pattern(&SHAPE.into()));
+/// assert!(!tinystr8!("a994").matches_pattern(&SHAPE.into()));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TinyShape {
+    min_len: u8,
+    max_len: u8,
+    // Each rule is a class and a mask with `0x80` in the byte of each position it
+    // applies to, with the first position in the least significant byte. The mask
+    // is truncated to match narrower TinyStrs.
+    rules: [(AsciiClass, u128); MAX_RULES],
+    rules_len: u8,
+}
+
+impl TinyShape {
+    /// Creates a shape accepting any string of `min_len..=max_len` characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_len` is zero, `min_len > max_len` or `max_len > 16`.
+    pub const fn new(min_len: usize, max_len: usize) -> Self {
+        assert!(
+            min_len != 0 && min_len <= max_len && max_len <= 16,
+            "invalid TinyShape length range"
+        );
+        Self {
+            min_len: min_len as u8,
+            max_len: max_len as u8,
+            rules: [(AsciiClass::NONE, 0); MAX_RULES],
+            rules_len: 0,
+        }
+    }
+
+    /// Requires the characters at the positions selected by `positions` to be in `class`.
+    const fn rule(mut self, class: AsciiClass, positions: u128) -> Self {
+        assert!(
+            (self.rules_len as usize) < MAX_RULES,
+            "too many rules in TinyShape"
+        );
+        self.rules[self.rules_len as usize] = (class, positions);
+        self.rules_len += 1;
+        self
+    }
+
+    /// Requires the character at `index` to be in `class`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than 16, or the shape already has 4 rules.
+    pub const fn at(self, index: usize, class: AsciiClass) -> Self {
+        assert!(index < 16, "TinyShape index out of bounds");
+        self.rule(class, 0x80 << (8 * index))
+    }
+
+    /// Requires the characters from `index` onwards to be in `class`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than 16, or the shape already has 4 rules.
+    pub const fn starting_at(self, index: usize, class: AsciiClass) -> Self {
+        assert!(index < 16, "TinyShape index out of bounds");
+        self.rule(class, w128::HIGH << (8 * index))
+    }
+
+    /// Requires all the characters to be in `class`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape already has 4 rules.
+    pub const fn all(self, class: AsciiClass) -> Self {
+        self.starting_at(0, class)
+    }
+}
+
+/// A declarative description of the allowed forms of a TinyStr, as an alternation of
+/// up to 4 [`TinyShape`]s.
+///
+/// Patterns are `const`-constructible, and a TinyStr is matched against each shape with
+/// a few mask operations per rule, using the `matches_pattern` method.
+///
+/// # Examples
+///
+/// ```
+/// use tinystr::{tinystr4, tinystr8, AsciiClass, TinyPattern, TinyShape};
+///
+/// // 2 letters or 3 digits.
+/// const REGION: TinyPattern = TinyPattern::new(TinyShape::new(2, 2).all(AsciiClass::ALPHABETIC))
+///     .or(TinyShape::new(3, 3).all(AsciiClass::DIGIT));
+///
+/// assert!(tinystr4!("US").matches_pattern(&REGION));
+/// assert!(tinystr4!("419").matches_pattern(&REGION));
+/// assert!(!tinystr4!("U5").matches_pattern(&REGION));
+/// assert!(!tinystr8!("USA").matches_pattern(&REGION));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TinyPattern {
+    shapes: [TinyShape; MAX_SHAPES],
+    shapes_len: u8,
+}
+
+impl TinyPattern {
+    /// Creates a pattern with a single shape.
+    pub const fn new(shape: TinyShape) -> Self {
+        Self {
+            shapes: [shape; MAX_SHAPES],
+            shapes_len: 1,
+        }
+    }
+
+    /// Adds an alternative shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern already has 4 shapes.
+    pub const fn or(mut self, shape: TinyShape) -> Self {
+        assert!(
+            (self.shapes_len as usize) < MAX_SHAPES,
+            "too many shapes in TinyPattern"
+        );
+        self.shapes[self.shapes_len as usize] = shape;
+        self.shapes_len += 1;
+        self
+    }
+}
+
+impl From<TinyShape> for TinyPattern {
+    #[inline(always)]
+    fn from(shape: TinyShape) -> Self {
+        Self::new(shape)
+    }
+}
+
+macro_rules! impl_pattern {
+    ($ty:ident, $int:ident, $swar:ident, $class_mask:ident) => {
+        impl $ty {
+            /// Checks if the value matches any of the shapes of `pattern`.
+            ///
+            /// See [`TinyPattern`] for examples.
+            pub const fn matches_pattern(self, pattern: &TinyPattern) -> bool {
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                let content = $swar::HIGH & $swar::low_bytes(len);
+                let mut i = 0;
+                'shapes: while i < pattern.shapes_len as usize {
+                    let shape = &pattern.shapes[i];
+                    i += 1;
+                    if len < shape.min_len as usize || len > shape.max_len as usize {
+                        continue;
+                    }
+                    let mut j = 0;
+                    while j < shape.rules_len as usize {
+                        let (class, positions) = shape.rules[j];
+                        let positions = positions as $int & content;
+                        if class.$class_mask(word) & positions != positions {
+                            continue 'shapes;
+                        }
+                        j += 1;
+                    }
+                    return true;
+                }
+                false
+            }
+        }
+    };
+}
+
+impl_pattern!(TinyStr4, u32, w32, mask_u32);
+impl_pattern!(TinyStr8, u64, w64, mask_u64);
+impl_pattern!(TinyStr16, u128, w128, mask_u128);
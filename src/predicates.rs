@@ -0,0 +1,230 @@
+use crate::swar::{w128, w32, w64};
+use crate::{AsciiClass, TinyStr16, TinyStr4, TinyStr8};
+
+macro_rules! impl_predicates {
+    ($ty:ident, $int:ident, $swar:ident, $class_mask:ident) => {
+        impl $ty {
+            /// Returns a high-bit mask of the bytes in `class`, excluding the padding,
+            /// with the first byte in the least significant position.
+            #[inline(always)]
+            pub(crate) const fn class_mask(self, class: AsciiClass) -> $int {
+                let word = self.as_unsigned().to_le();
+                class.$class_mask(word) & $swar::low_bytes($swar::len(word))
+            }
+
+            /// Returns `true` if every byte is in `class`.
+            #[inline(always)]
+            const fn all_in(self, class: AsciiClass) -> bool {
+                let content = $swar::HIGH & $swar::low_bytes(self.len());
+                self.class_mask(class) == content
+            }
+
+            /// Checks if the value has no ASCII uppercase letters: U+0041 'A' ..= U+005A 'Z'.
+            /// Other characters are ignored.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"te3t\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"Test\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_lowercase());
+            /// assert!(!s2.is_ascii_lowercase());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_lowercase(self) -> bool {
+                self.class_mask(AsciiClass::UPPERCASE) == 0
+            }
+
+            /// Checks if the value has no ASCII lowercase letters: U+0061 'a' ..= U+007A 'z'.
+            /// Other characters are ignored.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"TE3T\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"Test\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_uppercase());
+            /// assert!(!s2.is_ascii_uppercase());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_uppercase(self) -> bool {
+                self.class_mask(AsciiClass::LOWERCASE) == 0
+            }
+
+            /// Checks if the value is in ASCII title case: the first character is not an
+            /// ASCII lowercase letter, and the others are not ASCII uppercase letters.
+            /// Other characters are ignored.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"Te3t\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"TeSt\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_titlecase());
+            /// assert!(!s2.is_ascii_titlecase());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_titlecase(self) -> bool {
+                let first = $swar::HIGH & $swar::low_bytes(1);
+                (self.class_mask(AsciiClass::LOWERCASE) & first) == 0
+                    && (self.class_mask(AsciiClass::UPPERCASE) & !first) == 0
+            }
+
+            /// Checks if the value is composed of ASCII lowercase letters:
+            ///
+            ///  * U+0061 'a' ..= U+007A 'z'.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"test\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"te3t\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_alphabetic_lowercase());
+            /// assert!(!s2.is_ascii_alphabetic_lowercase());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_alphabetic_lowercase(self) -> bool {
+                self.all_in(AsciiClass::LOWERCASE)
+            }
+
+            /// Checks if the value is composed of ASCII hexadecimal digits:
+            ///
+            ///  * U+0030 '0' ..= U+0039 '9', or
+            ///  * U+0041 'A' ..= U+0046 'F', or
+            ///  * U+0061 'a' ..= U+0066 'f'.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"0fA9\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"0x1\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_hexdigit());
+            /// assert!(!s2.is_ascii_hexdigit());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_hexdigit(self) -> bool {
+                self.all_in(AsciiClass::HEXDIGIT)
+            }
+
+            /// Checks if the value is composed of ASCII graphic characters:
+            ///
+            ///  * U+0021 '!' ..= U+007E '~'.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"a-B!\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"a b\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_graphic());
+            /// assert!(!s2.is_ascii_graphic());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_graphic(self) -> bool {
+                self.all_in(AsciiClass::GRAPHIC)
+            }
+
+            /// Checks if the value is composed of ASCII punctuation characters:
+            ///
+            ///  * U+0021 ..= U+002F `! " # $ % & ' ( ) * + , - . /`, or
+            ///  * U+003A ..= U+0040 `: ; < = > ? @`, or
+            ///  * U+005B ..= U+0060 ``[ \ ] ^ _ ` ``, or
+            ///  * U+007B ..= U+007E `{ | } ~`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"-_.!\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"-a\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_punctuation());
+            /// assert!(!s2.is_ascii_punctuation());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_punctuation(self) -> bool {
+                self.all_in(AsciiClass::PUNCTUATION)
+            }
+
+            /// Checks if the value has no ASCII whitespace characters:
+            ///
+            ///  * U+0009 TAB, U+000A LF, U+000C FF, U+000D CR or U+0020 SPACE.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"a-b\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"a b\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_whitespace_free());
+            /// assert!(!s2.is_ascii_whitespace_free());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_whitespace_free(self) -> bool {
+                self.class_mask(AsciiClass::WHITESPACE) == 0
+            }
+
+            /// Checks if the value has no ASCII control characters:
+            ///
+            ///  * U+0001 ..= U+001F, or U+007F DELETE.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"a b\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"a\\tb\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.is_ascii_control_free());
+            /// assert!(!s2.is_ascii_control_free());
+            /// ```
+            #[inline]
+            pub const fn is_ascii_control_free(self) -> bool {
+                self.class_mask(AsciiClass::CONTROL) == 0
+            }
+        }
+    };
+}
+
+impl_predicates!(TinyStr4, u32, w32, mask_u32);
+impl_predicates!(TinyStr8, u64, w64, mask_u64);
+impl_predicates!(TinyStr16, u128, w128, mask_u128);
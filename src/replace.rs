@@ -0,0 +1,120 @@
+use crate::mutation::check_byte;
+use crate::swar::{w128, w32, w64};
+use crate::{
+    AsciiClass, Error, TinyStr16, TinyStr16OrEmpty, TinyStr4, TinyStr4OrEmpty, TinyStr8,
+    TinyStr8OrEmpty,
+};
+
+macro_rules! impl_replace {
+    ($ty:ident, $or_empty:ident, $int:ident, $swar:ident, $example:literal) => {
+        impl $ty {
+            /// Replaces every byte selected by a high-bit `mask` with `to`.
+            ///
+            /// The mask must exclude the padding, like those of `byte_mask` and `class_mask`.
+            #[inline(always)]
+            const fn replace_mask(self, mask: $int, to: u8) -> Result<Self, Error> {
+                // The index is that of the first replaced byte, if any.
+                let index = (mask.trailing_zeros() / 8) as usize % $swar::LEN;
+                if let Err(e) = check_byte(to, index) {
+                    return Err(e);
+                }
+                let word = self.as_unsigned().to_le();
+                let mask = $swar::expand(mask);
+                let result = (word & !mask) | ($swar::ONES * to as $int & mask);
+                // SAFETY: `to` is checked to be ASCII and not NUL, and the mask only selects
+                // content bytes, so the padding and the length are unchanged.
+                Ok(unsafe { Self::from_le_word_unchecked(result) })
+            }
+
+            /// Replaces every occurrence of the byte `from` with `to`.
+            ///
+            /// Returns an error if `to` is NUL or non-ASCII.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"a_b\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.replace_byte(b'_', b'-').unwrap(), "a-b");
+            /// ```
+            #[inline]
+            pub const fn replace_byte(self, from: u8, to: u8) -> Result<Self, Error> {
+                self.replace_mask(self.byte_mask(from), to)
+            }
+
+            /// Replaces every byte in `class` with `to`.
+            ///
+            /// Returns an error if `to` is NUL or non-ASCII.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{AsciiClass, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(
+            ///     s1.map_class(AsciiClass::PUNCTUATION, b'-').unwrap(),
+            #[doc = concat!("    \"", $example, "\".replace(|c: char| c.is_ascii_punctuation(), \"-\").as_str()")]
+            /// );
+            /// ```
+            #[inline]
+            pub const fn map_class(self, class: AsciiClass, to: u8) -> Result<Self, Error> {
+                self.replace_mask(self.class_mask(class), to)
+            }
+
+            /// Removes every occurrence of `byte`, moving the remaining bytes together.
+            ///
+            #[doc = concat!("The result is returned as a [`", stringify!($or_empty), "`], since it is empty")]
+            /// when every byte is removed.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"a-bc\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.remove_byte(b'-'), "abc");
+            /// ```
+            #[inline]
+            pub const fn remove_byte(self, byte: u8) -> $or_empty {
+                let word = self.as_unsigned().to_le();
+                // The kept bytes are moved together without branches, with the "compress"
+                // of Hacker's Delight (section 7-4) applied to whole bytes: in each round,
+                // the bytes with an odd prefix count of dropped bytes move down by `step`.
+                let mut keep =
+                    $swar::HIGH & $swar::low_bytes($swar::len(word)) & !self.byte_mask(byte);
+                let mut result = word & $swar::expand(keep);
+                let mut dropped = ($swar::HIGH & !keep) << 8;
+                let mut step = 1;
+                while step < $swar::LEN {
+                    let mut prefix = dropped ^ (dropped << 8);
+                    let mut shift = 16;
+                    while shift < <$int>::BITS {
+                        prefix ^= prefix << shift;
+                        shift *= 2;
+                    }
+                    let moved = prefix & keep;
+                    keep = (keep ^ moved) | (moved >> (8 * step));
+                    let bytes = result & $swar::expand(moved);
+                    result = (result ^ bytes) | (bytes >> (8 * step));
+                    dropped &= !prefix;
+                    step *= 2;
+                }
+                // SAFETY: The kept bytes are the content bytes other than `byte`, moved down
+                // in order, so they stay ASCII and NUL-padded.
+                unsafe { Self::or_empty_from_le_word_unchecked(result) }
+            }
+        }
+    };
+}
+
+impl_replace!(TinyStr4, TinyStr4OrEmpty, u32, w32, "a.b!");
+impl_replace!(TinyStr8, TinyStr8OrEmpty, u64, w64, "a.b!c");
+impl_replace!(TinyStr16, TinyStr16OrEmpty, u128, w128, "zh.Hant!TW");
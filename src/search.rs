@@ -0,0 +1,188 @@
+use crate::swar::{w128, w32, w64};
+use crate::{TinyStr16, TinyStr4, TinyStr8};
+
+macro_rules! impl_search {
+    ($ty:ident, $int:ident, $swar:ident, $example:literal) => {
+        impl $ty {
+            /// Returns a high-bit mask of the bytes equal to `byte`, excluding the padding.
+            #[inline(always)]
+            pub(crate) const fn byte_mask(self, byte: u8) -> $int {
+                let word = self.as_unsigned().to_le();
+                $swar::eq_mask(word, byte) & $swar::low_bytes($swar::len(word))
+            }
+
+            /// Returns the index of the first occurrence of `byte`, or `None` if there is none.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.find(b'e'), s1.as_str().find('e'));
+            /// assert_eq!(s1.find(b'?'), None);
+            /// ```
+            #[inline]
+            pub const fn find(self, byte: u8) -> Option<usize> {
+                let mask = self.byte_mask(byte);
+                if mask == 0 {
+                    return None;
+                }
+                Some((mask.trailing_zeros() / 8) as usize)
+            }
+
+            /// Returns the index of the last occurrence of `byte`, or `None` if there is none.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.rfind(b's'), s1.as_str().rfind('s'));
+            /// assert_eq!(s1.rfind(b'?'), None);
+            /// ```
+            #[inline]
+            pub const fn rfind(self, byte: u8) -> Option<usize> {
+                let mask = self.byte_mask(byte);
+                if mask == 0 {
+                    return None;
+                }
+                Some($swar::LEN - 1 - (mask.leading_zeros() / 8) as usize)
+            }
+
+            /// Returns `true` if the string contains `byte`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1.contains(b'T'));
+            /// assert!(!s1.contains(b'-'));
+            /// ```
+            #[inline]
+            pub const fn contains(self, byte: u8) -> bool {
+                self.byte_mask(byte) != 0
+            }
+
+            /// Returns the number of occurrences of `byte`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.count(b's'), s1.as_str().matches('s').count());
+            /// assert_eq!(s1.count(b'?'), 0);
+            /// ```
+            #[inline]
+            pub const fn count(self, byte: u8) -> usize {
+                self.byte_mask(byte).count_ones() as usize
+            }
+
+            /// Returns the index of the first byte that is in `bytes`, or `None` if there
+            /// is none.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"a_b\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.find_any(b"-_"), Some(1));
+            /// assert_eq!(s1.find_any(b"@?"), None);
+            /// ```
+            #[inline]
+            pub const fn find_any(self, bytes: &[u8]) -> Option<usize> {
+                let mut mask = 0;
+                let mut i = 0;
+                while i < bytes.len() {
+                    mask |= self.byte_mask(bytes[i]);
+                    i += 1;
+                }
+                if mask == 0 {
+                    return None;
+                }
+                Some((mask.trailing_zeros() / 8) as usize)
+            }
+
+            /// Returns `true` if the string starts with `prefix`.
+            ///
+            /// Another TinyStr of any width can be passed by reference, as it dereferences
+            /// to `&str`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{tinystr4, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert!(s1.starts_with(&\"", $example, "\"[..2]));")]
+            /// assert!(s1.starts_with(&tinystr4!("T")));
+            /// assert!(!s1.starts_with("e"));
+            /// ```
+            #[inline]
+            pub const fn starts_with(self, prefix: &str) -> bool {
+                let word = self.as_unsigned().to_le();
+                let prefix_len = prefix.len();
+                if prefix_len > $swar::len(word) {
+                    return false;
+                }
+                match $swar::from_bytes(prefix.as_bytes()) {
+                    Some(prefix) => word & $swar::low_bytes(prefix_len) == prefix,
+                    None => false,
+                }
+            }
+
+            /// Returns `true` if the string ends with `suffix`.
+            ///
+            /// Another TinyStr of any width can be passed by reference, as it dereferences
+            /// to `&str`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{tinystr4, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert!(s1.ends_with(&\"", $example, "\"[1..]));")]
+            /// assert!(s1.ends_with(&tinystr4!("s")));
+            /// assert!(!s1.ends_with("T"));
+            /// ```
+            #[inline]
+            pub const fn ends_with(self, suffix: &str) -> bool {
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                let suffix_len = suffix.len();
+                if suffix_len > len {
+                    return false;
+                }
+                match $swar::from_bytes(suffix.as_bytes()) {
+                    Some(suffix) => $swar::shr_bytes(word, len - suffix_len) == suffix,
+                    None => false,
+                }
+            }
+        }
+    };
+}
+
+impl_search!(TinyStr4, u32, w32, "Tsts");
+impl_search!(TinyStr8, u64, w64, "Tests");
+impl_search!(TinyStr16, u128, w128, "Testing sets");
@@ -0,0 +1,279 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::swar::{w128, w32, w64};
+use crate::{TinyStr16, TinyStr16OrEmpty, TinyStr4, TinyStr4OrEmpty, TinyStr8, TinyStr8OrEmpty};
+
+macro_rules! impl_slicing {
+    ($ty:ident, $or_empty:ident, $int:ident, $swar:ident, $example:literal) => {
+        impl $ty {
+            /// Builds a value from a little-endian word.
+            ///
+            /// # Safety
+            ///
+            /// The word must be non-zero, with ASCII bytes, and with no NUL byte before the
+            /// last non-NUL byte.
+            #[inline(always)]
+            pub(crate) const unsafe fn from_le_word_unchecked(word: $int) -> Self {
+                Self::from_native_unchecked($int::from_le(word))
+            }
+
+            /// Builds a possibly empty value from a little-endian word.
+            ///
+            /// # Safety
+            ///
+            /// The word must have ASCII bytes, with no NUL byte before the last non-NUL byte.
+            #[inline(always)]
+            pub(crate) const unsafe fn or_empty_from_le_word_unchecked(word: $int) -> $or_empty {
+                if word == 0 {
+                    $or_empty::EMPTY
+                } else {
+                    $or_empty::from_tinystr(Self::from_le_word_unchecked(word))
+                }
+            }
+
+            /// Returns the bytes in `start..end`, or `None` if the range is empty or out of bounds.
+            #[inline(always)]
+            const fn substring(self, start: usize, end: usize) -> Option<Self> {
+                let word = self.as_unsigned().to_le();
+                if start >= end || end > $swar::len(word) {
+                    return None;
+                }
+                // SAFETY: `start < end <= len`, so at least one content byte is kept. Bytes
+                // are only masked off and shifted down, so they stay ASCII and NUL-padded.
+                Some(unsafe {
+                    Self::from_le_word_unchecked($swar::shr_bytes(
+                        word & $swar::low_bytes(end),
+                        start,
+                    ))
+                })
+            }
+
+            #[doc = concat!("Returns the substring over a range of byte indices as a `", stringify!($ty), "`.")]
+            ///
+            /// Returns `None` if the range is out of bounds or empty.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.get(1..3).unwrap(), &s1[1..3]);
+            /// assert_eq!(s1.get(..).unwrap(), s1);
+            /// assert_eq!(s1.get(2..2), None);
+            /// assert_eq!(s1.get(..20), None);
+            /// ```
+            #[inline]
+            pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+                let start = match range.start_bound() {
+                    Bound::Included(&start) => start,
+                    Bound::Excluded(&start) => start.checked_add(1)?,
+                    Bound::Unbounded => 0,
+                };
+                let end = match range.end_bound() {
+                    Bound::Included(&end) => end.checked_add(1)?,
+                    Bound::Excluded(&end) => end,
+                    Bound::Unbounded => $swar::len(self.as_unsigned().to_le()),
+                };
+                self.substring(start, end)
+            }
+
+            /// Divides the string into two at a byte index.
+            ///
+            #[doc = concat!("Both halves are returned as [`", stringify!($or_empty), "`], since either is empty when")]
+            /// `mid` is zero or the length of the string. Returns `None` if `mid` is past the
+            /// end of the string.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{", stringify!($ty), ", ", stringify!($or_empty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// let (head, tail) = s1.split_at(2).unwrap();
+            /// assert_eq!(head, &s1[..2]);
+            /// assert_eq!(tail, &s1[2..]);
+            #[doc = concat!("assert_eq!(s1.split_at(0), Some((", stringify!($or_empty), "::EMPTY, s1.into())));")]
+            /// assert_eq!(s1.split_at(20), None);
+            /// ```
+            #[inline]
+            pub const fn split_at(self, mid: usize) -> Option<($or_empty, $or_empty)> {
+                let word = self.as_unsigned().to_le();
+                if mid > $swar::len(word) {
+                    return None;
+                }
+                // SAFETY: Both halves are masked or shifted down from a valid word, so their
+                // bytes stay ASCII and NUL-padded.
+                unsafe {
+                    Some((
+                        Self::or_empty_from_le_word_unchecked(word & $swar::low_bytes(mid)),
+                        Self::or_empty_from_le_word_unchecked($swar::shr_bytes(word, mid)),
+                    ))
+                }
+            }
+
+            /// Returns the first `n` bytes, or the whole string if it is shorter.
+            ///
+            /// Returns `None` if `n` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.first_n(2).unwrap(), &s1[..2]);
+            /// assert_eq!(s1.first_n(20), Some(s1));
+            /// assert_eq!(s1.first_n(0), None);
+            /// ```
+            #[inline]
+            pub const fn first_n(self, n: usize) -> Option<Self> {
+                if n == 0 {
+                    return None;
+                }
+                let word = self.as_unsigned().to_le();
+                // SAFETY: `n > 0` keeps the first byte, which is not NUL, and masking off the
+                // last bytes keeps the rest ASCII and NUL-padded.
+                Some(unsafe { Self::from_le_word_unchecked(word & $swar::low_bytes(n)) })
+            }
+
+            /// Returns the last `n` bytes, or the whole string if it is shorter.
+            ///
+            /// Returns `None` if `n` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.last_n(2).unwrap(), &s1[s1.len() - 2..]);
+            /// assert_eq!(s1.last_n(20), Some(s1));
+            /// assert_eq!(s1.last_n(0), None);
+            /// ```
+            #[inline]
+            pub const fn last_n(self, n: usize) -> Option<Self> {
+                if n == 0 {
+                    return None;
+                }
+                let word = self.as_unsigned().to_le();
+                let len = $swar::len(word);
+                if n >= len {
+                    return Some(self);
+                }
+                // SAFETY: `0 < n < len`, so the last `n` content bytes are kept, and shifting
+                // them down keeps them ASCII and NUL-padded.
+                Some(unsafe { Self::from_le_word_unchecked($swar::shr_bytes(word, len - n)) })
+            }
+
+            /// Returns the string with `prefix` removed, or `None` if it does not start
+            /// with `prefix`.
+            ///
+            #[doc = concat!("The remainder is returned as a [`", stringify!($or_empty), "`], since it is empty")]
+            /// when the whole string matches.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{", stringify!($ty), ", ", stringify!($or_empty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.strip_prefix(&s1[..1]).unwrap(), &s1[1..]);
+            #[doc = concat!("assert_eq!(s1.strip_prefix(&s1).unwrap(), ", stringify!($or_empty), "::EMPTY);")]
+            /// assert_eq!(s1.strip_prefix("?"), None);
+            /// ```
+            #[inline]
+            pub const fn strip_prefix(self, prefix: &str) -> Option<$or_empty> {
+                if !self.starts_with(prefix) {
+                    return None;
+                }
+                // SAFETY: Dropping the first bytes of a valid word keeps the rest ASCII and
+                // NUL-padded.
+                Some(unsafe {
+                    Self::or_empty_from_le_word_unchecked($swar::shr_bytes(
+                        self.as_unsigned().to_le(),
+                        prefix.len(),
+                    ))
+                })
+            }
+
+            /// Returns the string with `suffix` removed, or `None` if it does not end
+            /// with `suffix`.
+            ///
+            #[doc = concat!("The remainder is returned as a [`", stringify!($or_empty), "`], since it is empty")]
+            /// when the whole string matches.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{", stringify!($ty), ", ", stringify!($or_empty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.strip_suffix(&s1[1..]).unwrap(), &s1[..1]);
+            #[doc = concat!("assert_eq!(s1.strip_suffix(&s1).unwrap(), ", stringify!($or_empty), "::EMPTY);")]
+            /// assert_eq!(s1.strip_suffix("?"), None);
+            /// ```
+            #[inline]
+            pub const fn strip_suffix(self, suffix: &str) -> Option<$or_empty> {
+                if !self.ends_with(suffix) {
+                    return None;
+                }
+                let word = self.as_unsigned().to_le();
+                // SAFETY: Masking off the last bytes of a valid word keeps the rest ASCII and
+                // NUL-padded.
+                Some(unsafe {
+                    Self::or_empty_from_le_word_unchecked(
+                        word & $swar::low_bytes($swar::len(word) - suffix.len()),
+                    )
+                })
+            }
+
+            /// Returns the string with leading and trailing ASCII whitespace removed,
+            /// as defined by [`u8::is_ascii_whitespace`].
+            ///
+            /// Returns `None` if the string is only whitespace.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \" ab\\t\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \" \\n \".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert_eq!(s1.trim_ascii().unwrap(), "ab");
+            /// assert_eq!(s2.trim_ascii(), None);
+            /// ```
+            #[inline]
+            pub const fn trim_ascii(self) -> Option<Self> {
+                let word = self.as_unsigned().to_le();
+                let content =
+                    $swar::HIGH & !$swar::whitespace_mask(word) & $swar::low_bytes($swar::len(word));
+                if content == 0 {
+                    return None;
+                }
+                let start = (content.trailing_zeros() / 8) as usize;
+                let end = $swar::LEN - (content.leading_zeros() / 8) as usize;
+                self.substring(start, end)
+            }
+        }
+    };
+}
+
+impl_slicing!(TinyStr4, TinyStr4OrEmpty, u32, w32, "Test");
+impl_slicing!(TinyStr8, TinyStr8OrEmpty, u64, w64, "Testing");
+impl_slicing!(TinyStr16, TinyStr16OrEmpty, u128, w128, "Metamorphosis");
@@ -0,0 +1,151 @@
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+#[cfg(all(not(feature = "std"), not(test), feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), not(test), feature = "alloc"))]
+use alloc::vec;
+
+macro_rules! impl_sort_key {
+    ($ty:ident, $int:ident, $len:literal, $example:literal, $padded:literal) => {
+        impl $ty {
+            /// Returns an integer whose numeric order is the same as the string order.
+            ///
+            /// The bytes are packed big-endian, with the first character in the most
+            /// significant byte, so the key can be used for radix sorting or stored in a
+            /// byte-ordered database with [`to_be_bytes`](Self::to_be_bytes).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"ab\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let s2: ", stringify!($ty), " = \"b\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// assert!(s1 < s2);
+            /// assert!(s1.to_sort_key() < s2.to_sort_key());
+            #[doc = concat!("assert_eq!(", stringify!($ty), "::from_sort_key(s1.to_sort_key()), Ok(s1));")]
+            /// ```
+            #[inline(always)]
+            pub const fn to_sort_key(self) -> $int {
+                self.as_unsigned().to_be()
+            }
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from a key returned by [`", stringify!($ty), "::to_sort_key()`],")]
+            /// checking that it is a valid encoding.
+            #[inline]
+            pub const fn from_sort_key(key: $int) -> Result<Self, Error> {
+                Self::try_from_native_unsigned($int::from_be(key))
+            }
+
+            /// Returns the big-endian bytes of the sort key, which are the bytes of the
+            /// string padded with NUL.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert_eq!(&s1.to_be_bytes(), b\"", $example, $padded, "\");")]
+            #[doc = concat!("assert_eq!(", stringify!($ty), "::from_be_bytes(s1.to_be_bytes()), Ok(s1));")]
+            /// ```
+            #[inline(always)]
+            pub const fn to_be_bytes(self) -> [u8; $len] {
+                self.to_sort_key().to_be_bytes()
+            }
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from bytes returned by [`", stringify!($ty), "::to_be_bytes()`],")]
+            /// checking that they are a valid encoding.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; $len]) -> Result<Self, Error> {
+                Self::from_sort_key($int::from_be_bytes(bytes))
+            }
+
+            /// Sorts a slice in string order with a least significant digit radix sort on
+            /// the sort keys.
+            ///
+            /// This allocates a buffer of the same size as the slice, and skips the bytes
+            /// that are the same in every key. It is faster than `sort_unstable` for large
+            /// slices of short strings. Unlike `sort_unstable`, it takes linear time.
+            ///
+            /// This method is available with the `std` or `alloc` features.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            /// let mut strings = ["zh", "en", "de", "en"]
+            ///     .iter()
+            #[doc = concat!("    .map(|s| s.parse::<", stringify!($ty), ">().unwrap())")]
+            ///     .collect::<Vec<_>>();
+            #[doc = concat!(stringify!($ty), "::radix_sort(&mut strings);")]
+            ///
+            /// assert_eq!(strings, ["de", "en", "en", "zh"]);
+            /// ```
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            pub fn radix_sort(items: &mut [Self]) {
+                // The type is a transparent wrapper of a non-zero integer, so the slice can
+                // hold the sort keys, which are also non-zero, while it is being sorted.
+                let keys = unsafe {
+                    core::slice::from_raw_parts_mut(items.as_mut_ptr() as *mut $int, items.len())
+                };
+                for key in keys.iter_mut() {
+                    *key = key.to_be();
+                }
+
+                // Count the values of every byte in one pass. Keys are ASCII, so each
+                // byte has 128 values, with NUL padding first.
+                let mut counts = [[0; 128]; $len];
+                for &key in keys.iter() {
+                    for (byte, count) in counts.iter_mut().enumerate() {
+                        count[(key >> (8 * byte)) as u8 as usize] += 1;
+                    }
+                }
+
+                // Sort by each byte, from the last one, moving the keys back and forth
+                // between the slice and the buffer.
+                let mut buffer = vec![0; keys.len()];
+                let mut in_buffer = false;
+                for (byte, count) in counts.iter_mut().enumerate() {
+                    if count.iter().any(|&n| n == keys.len()) {
+                        continue;
+                    }
+                    let mut start = 0;
+                    for n in count.iter_mut() {
+                        start += *n;
+                        *n = start - *n;
+                    }
+                    let (from, to) = if in_buffer {
+                        (&buffer[..], &mut keys[..])
+                    } else {
+                        (&keys[..], &mut buffer[..])
+                    };
+                    for &key in from {
+                        let n = &mut count[(key >> (8 * byte)) as u8 as usize];
+                        to[*n] = key;
+                        *n += 1;
+                    }
+                    in_buffer = !in_buffer;
+                }
+                if in_buffer {
+                    keys.copy_from_slice(&buffer);
+                }
+
+                for key in keys.iter_mut() {
+                    *key = $int::from_be(*key);
+                }
+            }
+        }
+    };
+}
+
+impl_sort_key!(TinyStr4, u32, 4, "en", "\\0\\0");
+impl_sort_key!(TinyStr8, u64, 8, "en-US", "\\0\\0\\0");
+impl_sort_key!(TinyStr16, u128, 16, "metamorphosis", "\\0\\0\\0");
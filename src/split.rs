@@ -0,0 +1,174 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::swar::{w128, w32, w64};
+use crate::{
+    Error, TinyStr16, TinyStr16OrEmpty, TinyStr4, TinyStr4OrEmpty, TinyStr8, TinyStr8OrEmpty,
+};
+
+/// An iterator over the parts of a TinyStr separated by a byte, as TinyStrs of type `T`.
+///
+/// This is created by the `split_byte` and `splitn` methods on [`TinyStr4`], [`TinyStr8`]
+/// and [`TinyStr16`]. Each part is returned as an `Err` with `Error::Empty` or
+/// `Error::TooLong` if it is empty or too long for `T`.
+#[derive(Copy, Clone, Debug)]
+pub struct SplitByte<T> {
+    // Little-endian, NUL-padded word holding the bytes not yet returned.
+    word: u128,
+    len: usize,
+    separator: u8,
+    // The number of parts left to return before the remainder is returned whole.
+    remaining: usize,
+    finished: bool,
+    part: PhantomData<T>,
+}
+
+impl<T> SplitByte<T> {
+    #[inline(always)]
+    pub(crate) const fn new(word: u128, separator: u8, limit: usize) -> Self {
+        Self {
+            word,
+            len: w128::len(word),
+            separator,
+            remaining: limit,
+            finished: limit == 0,
+            part: PhantomData,
+        }
+    }
+
+    /// Returns the next part as a little-endian word, and its length.
+    #[inline]
+    fn next_word(&mut self) -> Option<(u128, usize)> {
+        if self.finished {
+            return None;
+        }
+        let mask = w128::eq_mask(self.word, self.separator) & w128::low_bytes(self.len);
+        self.remaining -= 1;
+        if mask == 0 || self.remaining == 0 {
+            self.finished = true;
+            return Some((self.word, self.len));
+        }
+        let index = (mask.trailing_zeros() / 8) as usize;
+        let part = self.word & w128::low_bytes(index);
+        self.word = w128::shr_bytes(self.word, index + 1);
+        self.len -= index + 1;
+        Some((part, index))
+    }
+}
+
+macro_rules! impl_split_iter {
+    ($part:ident, $int:ident, $swar:ident) => {
+        impl Iterator for SplitByte<$part> {
+            type Item = Result<$part, Error>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let (word, len) = self.next_word()?;
+                if len == 0 || len > $swar::LEN {
+                    return Some(Err(Error::invalid_size(len, $swar::LEN)));
+                }
+                // SAFETY: The part holds `len` content bytes of a valid word, followed by
+                // NUL, and `0 < len <= LEN` means that it is non-empty and fits.
+                Some(Ok(unsafe { $part::from_le_word_unchecked(word as $int) }))
+            }
+        }
+
+        impl FusedIterator for SplitByte<$part> {}
+    };
+}
+
+impl_split_iter!(TinyStr4, u32, w32);
+impl_split_iter!(TinyStr8, u64, w64);
+impl_split_iter!(TinyStr16, u128, w128);
+
+macro_rules! impl_split {
+    ($ty:ident, $or_empty:ident, $part:ident, $swar:ident, $example:literal) => {
+        impl $ty {
+            /// Returns an iterator over the parts of the string separated by `separator`,
+            #[doc = concat!("as [`", stringify!($part), "`]s.")]
+            ///
+            /// Parts that are empty or too long are returned as `Error::Empty` or
+            /// `Error::TooLong`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// let parts: Result<Vec<_>, Error> = s1.split_byte(b'-').collect();
+            /// let parts = parts.expect("Failed to split.");
+            #[doc = concat!("assert!(parts.iter().map(|p| p.as_str()).eq(\"", $example, "\".split('-')));")]
+            /// ```
+            #[inline]
+            pub const fn split_byte(self, separator: u8) -> SplitByte<$part> {
+                SplitByte::new(self.as_unsigned().to_le() as u128, separator, usize::MAX)
+            }
+
+            /// Returns an iterator over at most `n` parts of the string separated by
+            #[doc = concat!("`separator`, as [`", stringify!($part), "`]s. The last part is the rest of the string.")]
+            ///
+            /// Parts that are empty or too long are returned as `Error::Empty` or
+            /// `Error::TooLong`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"a-b-\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// let mut parts = s1.splitn(2, b'-');
+            /// assert_eq!(parts.next().unwrap().unwrap(), "a");
+            /// assert_eq!(parts.next().unwrap().unwrap(), "b-");
+            /// assert_eq!(parts.next(), None);
+            /// ```
+            #[inline]
+            pub const fn splitn(self, n: usize, separator: u8) -> SplitByte<$part> {
+                SplitByte::new(self.as_unsigned().to_le() as u128, separator, n)
+            }
+
+            /// Splits the string on the last occurrence of `separator`, or returns `None`
+            /// if there is none.
+            ///
+            #[doc = concat!("The parts are returned as [`", stringify!($or_empty), "`]s, since they are empty")]
+            /// when the separator is at the start or the end of the string.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            /// let (head, tail) = s1.rsplit_once(b'-').expect("No separator.");
+            #[doc = concat!("assert_eq!((head.as_str(), tail.as_str()), \"", $example, "\".rsplit_once('-').unwrap());")]
+            /// assert_eq!(s1.rsplit_once(b'_'), None);
+            /// ```
+            #[inline]
+            pub const fn rsplit_once(self, separator: u8) -> Option<($or_empty, $or_empty)> {
+                let index = match self.rfind(separator) {
+                    Some(index) => index,
+                    None => return None,
+                };
+                let word = self.as_unsigned().to_le();
+                // SAFETY: Both parts are masked or shifted down from a valid word, so their
+                // bytes stay ASCII and NUL-padded.
+                unsafe {
+                    Some((
+                        Self::or_empty_from_le_word_unchecked(word & $swar::low_bytes(index)),
+                        Self::or_empty_from_le_word_unchecked($swar::shr_bytes(word, index + 1)),
+                    ))
+                }
+            }
+        }
+    };
+}
+
+impl_split!(TinyStr4, TinyStr4OrEmpty, TinyStr4, w32, "a-b");
+impl_split!(TinyStr8, TinyStr8OrEmpty, TinyStr4, w64, "zh-Hant");
+impl_split!(TinyStr16, TinyStr16OrEmpty, TinyStr8, w128, "zh-Hant-TW");
@@ -0,0 +1,123 @@
+//! Helpers for SIMD-within-a-register operations on the integers backing TinyStrs.
+//!
+//! All functions take and return words in little-endian order, that is, with the first
+//! character in the least significant byte, regardless of the target endianness.
+//! Use `to_le()` on the result of `as_unsigned()` to obtain such a word, and `from_le()`
+//! to go back.
+//!
+//! Byte masks are either "high-bit" masks, with `0x80` set in each selected byte, or
+//! full byte masks, with `0xff` in each selected byte.
+
+macro_rules! swar_helpers {
+    ($mod:ident, $int:ident, $len:literal) => {
+        #[allow(dead_code)]
+        pub(crate) mod $mod {
+            /// The number of bytes in the word.
+            pub const LEN: usize = $len;
+            /// `0x01` in every byte.
+            pub const ONES: $int = <$int>::MAX / 0xff;
+            /// `0x80` in every byte.
+            pub const HIGH: $int = ONES * 0x80;
+
+            /// Returns the number of non-NUL bytes in a NUL-padded word.
+            #[inline(always)]
+            pub const fn len(word: $int) -> usize {
+                ($len - word.leading_zeros() / 8) as usize
+            }
+
+            /// Returns a full byte mask selecting the first `n` bytes.
+            #[inline(always)]
+            pub const fn low_bytes(n: usize) -> $int {
+                if n >= $len {
+                    <$int>::MAX
+                } else {
+                    (1 << (8 * n)) - 1
+                }
+            }
+
+            /// Drops the first `n` bytes, moving the remaining ones to the front.
+            #[inline(always)]
+            pub const fn shr_bytes(word: $int, n: usize) -> $int {
+                if n >= $len {
+                    0
+                } else {
+                    word >> (8 * n)
+                }
+            }
+
+            /// Moves all bytes `n` positions towards the end, dropping the last `n` bytes.
+            #[inline(always)]
+            pub const fn shl_bytes(word: $int, n: usize) -> $int {
+                if n >= $len {
+                    0
+                } else {
+                    word << (8 * n)
+                }
+            }
+
+            /// Returns the byte at index `i`, which must be less than `LEN`.
+            #[inline(always)]
+            pub const fn byte(word: $int, i: usize) -> u8 {
+                (word >> (8 * i)) as u8
+            }
+
+            /// Expands a high-bit mask into a full byte mask.
+            #[inline(always)]
+            pub const fn expand(high: $int) -> $int {
+                (high >> 7) * 0xff
+            }
+
+            /// Returns a high-bit mask of the bytes equal to `byte`.
+            #[inline(always)]
+            pub const fn eq_mask(word: $int, byte: u8) -> $int {
+                let x = word ^ (ONES * byte as $int);
+                !(((x & !HIGH) + !HIGH) | x) & HIGH
+            }
+
+            /// Returns a high-bit mask of the bytes in `lo..=hi`.
+            ///
+            /// Only valid for words of ASCII bytes and `lo <= hi <= 0x7f`.
+            #[inline(always)]
+            pub const fn range_mask(word: $int, lo: u8, hi: u8) -> $int {
+                let ge_lo = word + ONES * (0x80 - lo) as $int;
+                let gt_hi = word + ONES * (0x7f - hi) as $int;
+                ge_lo & !gt_hi & HIGH
+            }
+
+            /// Maps the ASCII uppercase letters to lowercase.
+            ///
+            /// Only valid for words of ASCII bytes.
+            #[inline(always)]
+            pub const fn to_lowercase(word: $int) -> $int {
+                word | (range_mask(word, b'A', b'Z') >> 2)
+            }
+
+            /// Returns a high-bit mask of the ASCII whitespace bytes, as defined by
+            /// [`u8::is_ascii_whitespace`].
+            #[inline(always)]
+            pub const fn whitespace_mask(word: $int) -> $int {
+                range_mask(word, b'\t', b'\n') | range_mask(word, 0x0c, b'\r') | eq_mask(word, b' ')
+            }
+
+            /// Loads up to `LEN` bytes into a NUL-padded word, or returns `None` if there
+            /// are too many bytes. The bytes are not validated.
+            #[inline(always)]
+            pub const fn from_bytes(bytes: &[u8]) -> Option<$int> {
+                if bytes.len() > $len {
+                    return None;
+                }
+                let mut word = 0;
+                let mut i = 0;
+                while i < bytes.len() {
+                    word |= (bytes[i] as $int) << (8 * i);
+                    i += 1;
+                }
+                Some(word)
+            }
+        }
+    };
+}
+
+swar_helpers!(w32, u32, 4);
+swar_helpers!(w64, u64, 8);
+swar_helpers!(w128, u128, 16);
@@ -0,0 +1,274 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+use std::ops::Deref;
+
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+/// A tiny byte string of 0 to `N` arbitrary bytes, with `N` up to 255.
+///
+/// Unlike the ASCII-only types, `TinyBytes` stores its length explicitly, in a byte
+/// following the data, so it allows NUL and non-ASCII bytes and the empty string.
+/// This makes it suitable for binary tags, hashes and protocol magic numbers.
+/// Bytes past the length are always zero, and `zerovec` rejects other values with
+/// `Error::InvalidPadding`.
+///
+/// # Examples
+///
+/// ```
+/// use tinystr::TinyBytes;
+///
+/// let magic = TinyBytes::<8>::from_bytes(b"\x89PNG\r\n\x1a\n")
+///     .expect("Failed to parse.");
+///
+/// assert_eq!(magic.len(), 8);
+/// assert_eq!(&magic[1..4], b"PNG");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct TinyBytes<const N: usize> {
+    bytes: [u8; N],
+    len: u8,
+}
+
+impl<const N: usize> TinyBytes<N> {
+    // The length has to fit in the trailing `u8`.
+    const CAPACITY_CHECK: () = assert!(N <= u8::MAX as usize, "TinyBytes<N> requires N <= 255");
+
+    /// An empty `TinyBytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyBytes;
+    ///
+    /// assert!(TinyBytes::<4>::EMPTY.is_empty());
+    /// assert_eq!(TinyBytes::<4>::EMPTY, TinyBytes::default());
+    /// ```
+    pub const EMPTY: Self = Self {
+        bytes: [0; N],
+        len: 0,
+    };
+
+    /// Creates a `TinyBytes` from a byte slice of at most `N` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::{Error, TinyBytes};
+    ///
+    /// let b1 = TinyBytes::<4>::from_bytes(&[0, 159, 146])
+    ///     .expect("Failed to parse.");
+    ///
+    /// assert_eq!(&*b1, &[0, 159, 146]);
+    /// assert_eq!(
+    ///     TinyBytes::<4>::from_bytes(b"12345"),
+    ///     Err(Error::TooLong { len: 5, max: 4 })
+    /// );
+    /// ```
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::CAPACITY_CHECK;
+        if bytes.len() > N {
+            return Err(Error::TooLong {
+                len: bytes.len(),
+                max: N,
+            });
+        }
+        let mut result = Self::EMPTY;
+        let mut i = 0;
+        while i < bytes.len() {
+            result.bytes[i] = bytes[i];
+            i += 1;
+        }
+        result.len = bytes.len() as u8;
+        Ok(result)
+    }
+
+    /// Creates a `TinyBytes` holding exactly `N` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyBytes;
+    ///
+    /// const MAGIC: TinyBytes<4> = TinyBytes::from_array(*b"\0asm");
+    ///
+    /// assert_eq!(MAGIC.len(), 4);
+    /// ```
+    pub const fn from_array(bytes: [u8; N]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::CAPACITY_CHECK;
+        Self {
+            bytes,
+            len: N as u8,
+        }
+    }
+
+    /// Returns the number of bytes stored.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns `true` if no bytes are stored.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Extracts a byte slice containing the entire `TinyBytes`.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.deref()
+    }
+}
+
+impl<const N: usize> Default for TinyBytes<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl<const N: usize> fmt::Debug for TinyBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("b\"")?;
+        for &byte in self.deref() {
+            for c in core::ascii::escape_default(byte) {
+                f.write_char(c as char)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl<const N: usize> Deref for TinyBytes<N> {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &[u8] {
+        &self.bytes[..self.len()]
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for TinyBytes<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.deref()
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8]> for TinyBytes<N> {
+    #[inline(always)]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.deref() == *other
+    }
+}
+
+impl<const N: usize> PartialOrd for TinyBytes<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for TinyBytes<N> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for TinyBytes<N> {
+    #[inline(always)]
+    fn from(bytes: [u8; N]) -> Self {
+        Self::from_array(bytes)
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for TinyBytes<N> {
+    type Error = Error;
+
+    #[inline(always)]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+macro_rules! impl_tinybytes_conversions {
+    ($size:literal, $tiny:ident) => {
+        impl From<$tiny> for TinyBytes<$size> {
+            #[inline(always)]
+            fn from(tiny: $tiny) -> Self {
+                let mut bytes = [0; $size];
+                bytes[..tiny.len()].copy_from_slice(tiny.as_bytes());
+                Self {
+                    bytes,
+                    len: tiny.len() as u8,
+                }
+            }
+        }
+
+        impl TryFrom<TinyBytes<$size>> for $tiny {
+            type Error = Error;
+
+            #[inline(always)]
+            fn try_from(bytes: TinyBytes<$size>) -> Result<Self, Self::Error> {
+                $tiny::from_bytes(&bytes)
+            }
+        }
+    };
+}
+
+impl_tinybytes_conversions!(4, TinyStr4);
+impl_tinybytes_conversions!(8, TinyStr8);
+impl_tinybytes_conversions!(16, TinyStr16);
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for TinyBytes<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_bytes(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for TinyBytes<N> {
+    fn deserialize<D>(deserializer: D) -> Result<TinyBytes<N>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{Error as SerdeError, SeqAccess, Visitor};
+
+        struct TinyBytesVisitor<const N: usize>;
+
+        impl<'de, const N: usize> Visitor<'de> for TinyBytesVisitor<N> {
+            type Value = TinyBytes<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "at most {} bytes", N)
+            }
+
+            fn visit_bytes<E: SerdeError>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+                TinyBytes::from_bytes(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut result = TinyBytes::EMPTY;
+                while let Some(byte) = seq.next_element()? {
+                    if result.len() == N {
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+                    result.bytes[result.len()] = byte;
+                    result.len += 1;
+                }
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_bytes(TinyBytesVisitor::<N>)
+    }
+}
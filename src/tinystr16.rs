@@ -41,6 +41,56 @@ impl TinyStr16 {
         tinystr_raw::try_u128_from_bytes(bytes).map(Self)
     }
 
+    /// Creates a TinyStr16 from a NUL-padded array, such as a fixed-size field of a C struct.
+    ///
+    /// The array must start with at least one non-NUL ASCII byte, and only NUL bytes
+    /// may follow the first NUL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::{Error, TinyStr16};
+    ///
+    /// let s1 = TinyStr16::from_padded_bytes(b"metamorphosis\0\0\0")
+    ///     .expect("Failed to parse.");
+    ///
+    /// assert_eq!(s1, "metamorphosis");
+    /// assert_eq!(
+    ///     TinyStr16::from_padded_bytes(&[0; 16]),
+    ///     Err(Error::Empty)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_padded_bytes(bytes: &[u8; 16]) -> Result<Self, Error> {
+        let word = u128::from_ne_bytes(*bytes);
+        if word & 0x8080_8080_8080_8080_8080_8080_8080_8080 != 0 {
+            return Err(Error::invalid_byte(bytes, 0));
+        }
+        match tinystr_raw::nul_padded_len(bytes) {
+            Ok(0) => Err(Error::Empty),
+            // The bytes are ASCII, with only NUL padding after the first NUL.
+            Ok(_) => Ok(Self(unsafe { NonZeroU128::new_unchecked(word) })),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the bytes of this TinyStr16, padded with NUL to 16 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStr16;
+    ///
+    /// let s1: TinyStr16 = "metamorphosis".parse()
+    ///     .expect("Failed to parse.");
+    ///
+    /// assert_eq!(&s1.to_padded_bytes(), b"metamorphosis\0\0\0");
+    /// ```
+    #[inline(always)]
+    pub const fn to_padded_bytes(self) -> [u8; 16] {
+        self.0.get().to_ne_bytes()
+    }
+
     /// An unsafe constructor intended for cases where the consumer
     /// guarantees that the input is a little endian integer which
     /// is a correct representation of a `TinyStr16` string.
@@ -0,0 +1,200 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+macro_rules! tinystr_or_empty {
+    ($ty:ident, $tiny:ident, $int:ident, $len:literal, $example:literal) => {
+        #[doc = concat!("A tiny string that is from 0 to ", $len, " non-NUL ASCII characters.")]
+        ///
+        #[doc = concat!("This is a companion of [`", stringify!($tiny), "`] which also allows the empty")]
+        /// string, so it implements `Default`. It has the same size as the integer backing it, and
+        #[doc = concat!("converts losslessly to and from `Option<", stringify!($tiny), ">`.")]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use tinystr::{", stringify!($ty), ", ", stringify!($tiny), "};")]
+        ///
+        #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+        ///     .expect("Failed to parse.");
+        #[doc = concat!("let s2 = ", stringify!($ty), "::default();")]
+        ///
+        #[doc = concat!("assert_eq!(s1, \"", $example, "\");")]
+        /// assert_eq!(s2, "");
+        #[doc = concat!("assert_eq!(Option::<", stringify!($tiny), ">::from(s2), None);")]
+        /// ```
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+        #[repr(transparent)]
+        pub struct $ty($int);
+
+        impl $ty {
+            /// The empty string.
+            pub const EMPTY: Self = Self(0);
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from a byte slice, which may be empty.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1 = ", stringify!($ty), "::from_bytes(b\"\")")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert_eq!(s1, ", stringify!($ty), "::EMPTY);")]
+            /// ```
+            #[inline(always)]
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                if bytes.is_empty() {
+                    return Ok(Self::EMPTY);
+                }
+                $tiny::from_bytes(bytes).map(Self::from_tinystr)
+            }
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from a NUL-padded array, which may be all NUL.")]
+            ///
+            #[doc = concat!("See [`", stringify!($tiny), "::from_padded_bytes`].")]
+            #[inline]
+            pub const fn from_padded_bytes(bytes: &[u8; $len]) -> Result<Self, Error> {
+                match $tiny::from_padded_bytes(bytes) {
+                    Ok(tiny) => Ok(Self::from_tinystr(tiny)),
+                    Err(Error::Empty) => Ok(Self::EMPTY),
+                    Err(e) => Err(e),
+                }
+            }
+
+            #[doc = concat!("Returns the bytes of this ", stringify!($ty), ", padded with NUL to ", $len, " bytes.")]
+            #[inline(always)]
+            pub const fn to_padded_bytes(self) -> [u8; $len] {
+                self.0.to_ne_bytes()
+            }
+
+            #[doc = concat!("Wraps a non-empty [`", stringify!($tiny), "`].")]
+            #[inline(always)]
+            pub const fn from_tinystr(tiny: $tiny) -> Self {
+                Self(tiny.as_unsigned())
+            }
+
+            #[doc = concat!("Returns the non-empty [`", stringify!($tiny), "`], or `None` for the empty string.")]
+            #[inline(always)]
+            pub const fn into_option(self) -> Option<$tiny> {
+                if self.0 == 0 {
+                    None
+                } else {
+                    Some(unsafe { $tiny::from_native_unchecked(self.0) })
+                }
+            }
+
+            /// Returns `true` if this is the empty string.
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            #[doc = concat!("Extracts a string slice containing the entire `", stringify!($ty), "`.")]
+            #[inline(always)]
+            pub fn as_str(&self) -> &str {
+                self.deref()
+            }
+
+            #[doc = concat!("Gets a representation of this ", stringify!($ty), " as a primitive, valid for the")]
+            /// current machine. The empty string is represented by zero.
+            pub const fn as_unsigned(&self) -> $int {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $ty {
+            #[inline(always)]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.deref().fmt(f)
+            }
+        }
+
+        impl fmt::Debug for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{:?}", self.deref())
+            }
+        }
+
+        impl Deref for $ty {
+            type Target = str;
+
+            #[inline(always)]
+            fn deref(&self) -> &str {
+                let word = self.0;
+                #[cfg(target_endian = "little")]
+                let len = ($len - word.leading_zeros() / 8) as usize;
+                #[cfg(target_endian = "big")]
+                let len = ($len - word.trailing_zeros() / 8) as usize;
+                unsafe {
+                    let slice = core::slice::from_raw_parts(&self.0 as *const _ as *const u8, len);
+                    std::str::from_utf8_unchecked(slice)
+                }
+            }
+        }
+
+        impl PartialEq<&str> for $ty {
+            #[inline(always)]
+            fn eq(&self, other: &&str) -> bool {
+                self.deref() == *other
+            }
+        }
+
+        impl PartialOrd for $ty {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $ty {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.to_ne_bytes().cmp(&other.0.to_ne_bytes())
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = Error;
+
+            #[inline(always)]
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                Self::from_bytes(text.as_bytes())
+            }
+        }
+
+        impl From<$tiny> for $ty {
+            #[inline(always)]
+            fn from(tiny: $tiny) -> Self {
+                Self::from_tinystr(tiny)
+            }
+        }
+
+        impl From<Option<$tiny>> for $ty {
+            #[inline(always)]
+            fn from(option: Option<$tiny>) -> Self {
+                match option {
+                    Some(tiny) => Self::from_tinystr(tiny),
+                    None => Self::EMPTY,
+                }
+            }
+        }
+
+        impl From<$ty> for Option<$tiny> {
+            #[inline(always)]
+            fn from(value: $ty) -> Self {
+                value.into_option()
+            }
+        }
+
+        serde_impl!($ty, $int);
+    };
+}
+
+tinystr_or_empty!(TinyStr4OrEmpty, TinyStr4, u32, 4, "Test");
+tinystr_or_empty!(TinyStr8OrEmpty, TinyStr8, u64, 8, "Testing");
+tinystr_or_empty!(TinyStr16OrEmpty, TinyStr16, u128, 16, "Metamorphosis");
@@ -26,6 +26,11 @@ pub use alloc::string::String;
 ///
 /// assert_eq!(s1, "Testing");
 /// ```
+///
+/// A `Heap` value can be empty if it is built directly. The `is_ascii_*` predicates that
+/// check that every character is in a class, such as `is_ascii_alphabetic`, return `false`
+/// for an empty string, and those that check that no character is in a class, such as
+/// `is_ascii_lowercase`, return `true`.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 pub enum TinyStrAuto {
     /// Up to 16 characters stored on the stack.
@@ -34,6 +39,68 @@ pub enum TinyStrAuto {
     Heap(String),
 }
 
+macro_rules! auto_predicates {
+    ($($(#[$attr:meta])* $name:ident => $heap:expr;)*) => {
+        impl TinyStrAuto {
+            $(
+                $(#[$attr])*
+                #[doc = concat!("See [`TinyStr16::", stringify!($name), "`].")]
+                pub fn $name(&self) -> bool {
+                    match self {
+                        TinyStrAuto::Tiny(value) => value.$name(),
+                        TinyStrAuto::Heap(value) => {
+                            let f: fn(&[u8]) -> bool = $heap;
+                            f(value.as_bytes())
+                        }
+                    }
+                }
+            )*
+        }
+    };
+}
+
+auto_predicates! {
+    /// Checks if the value is composed of ASCII alphabetic characters.
+    ///
+    is_ascii_alphabetic => |b| !b.is_empty() && b.iter().all(u8::is_ascii_alphabetic);
+    /// Checks if the value is composed of ASCII alphanumeric characters.
+    ///
+    is_ascii_alphanumeric => |b| !b.is_empty() && b.iter().all(u8::is_ascii_alphanumeric);
+    /// Checks if the value is composed of ASCII decimal digits.
+    ///
+    is_ascii_numeric => |b| !b.is_empty() && b.iter().all(u8::is_ascii_digit);
+    /// Checks if the value has no ASCII uppercase letters.
+    ///
+    is_ascii_lowercase => |b| !b.iter().any(u8::is_ascii_uppercase);
+    /// Checks if the value has no ASCII lowercase letters.
+    ///
+    is_ascii_uppercase => |b| !b.iter().any(u8::is_ascii_lowercase);
+    /// Checks if the value is in ASCII title case.
+    ///
+    is_ascii_titlecase => |b| {
+        !matches!(b.first(), Some(c) if c.is_ascii_lowercase())
+            && !b.iter().skip(1).any(u8::is_ascii_uppercase)
+    };
+    /// Checks if the value is composed of ASCII lowercase letters.
+    ///
+    is_ascii_alphabetic_lowercase => |b| !b.is_empty() && b.iter().all(u8::is_ascii_lowercase);
+    /// Checks if the value is composed of ASCII hexadecimal digits.
+    ///
+    is_ascii_hexdigit => |b| !b.is_empty() && b.iter().all(u8::is_ascii_hexdigit);
+    /// Checks if the value is composed of ASCII graphic characters.
+    ///
+    is_ascii_graphic => |b| !b.is_empty() && b.iter().all(u8::is_ascii_graphic);
+    /// Checks if the value is composed of ASCII punctuation characters.
+    ///
+    is_ascii_punctuation => |b| !b.is_empty() && b.iter().all(u8::is_ascii_punctuation);
+    /// Checks if the value has no ASCII whitespace characters.
+    ///
+    is_ascii_whitespace_free => |b| !b.iter().any(u8::is_ascii_whitespace);
+    /// Checks if the value has no ASCII control characters.
+    ///
+    is_ascii_control_free => |b| !b.iter().any(u8::is_ascii_control);
+}
+
 impl fmt::Display for TinyStrAuto {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -67,10 +134,10 @@ impl FromStr for TinyStrAuto {
     fn from_str(text: &str) -> Result<Self, Self::Err> {
         if text.len() <= 16 {
             TinyStr16::from_str(text).map(TinyStrAuto::Tiny)
-        } else if text.is_ascii() {
+        } else if text.is_ascii() && !text.as_bytes().contains(&0) {
             Ok(TinyStrAuto::Heap(text.into()))
         } else {
-            Err(Error::NonAscii)
+            Err(Error::invalid_byte(text.as_bytes(), 0))
         }
     }
 }
@@ -81,7 +148,7 @@ impl serde::Serialize for TinyStrAuto {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self)
+        serializer.serialize_str(self)
     }
 }
 
@@ -91,10 +158,10 @@ impl<'de> serde::Deserialize<'de> for TinyStrAuto {
     where
         D: serde::Deserializer<'de>,
     {
-        use serde::de::Error as SerdeError;
         use alloc::borrow::Cow;
         #[cfg(not(feature = "std"))]
         use alloc::string::ToString;
+        use serde::de::Error as SerdeError;
 
         let x: Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
         x.parse()
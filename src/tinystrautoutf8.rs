@@ -0,0 +1,326 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::tinystrauto::String;
+use crate::{TinyStr16, TinyStrAuto};
+
+/// A UTF-8 string that is tiny when it is ASCII and <= 16 chars, and a String otherwise.
+///
+/// Unlike [`TinyStrAuto`], parsing never fails: empty, long and non-ASCII strings are
+/// all stored on the heap. The inline variant is only used for non-empty ASCII strings
+/// of up to 16 characters, so that each string has exactly one representation.
+///
+/// The case-mapping and predicate methods follow ASCII-only semantics, mirroring
+/// [`str::to_ascii_lowercase`] and friends: non-ASCII characters are never changed by
+/// the `to_ascii_*` methods, and cause the `is_ascii_*` predicates to return `false`.
+/// As with [`TinyStrAuto`], the empty string is not composed of any class, so the
+/// predicates also return `false` for it.
+///
+/// # Examples
+///
+/// ```
+/// use tinystr::TinyStrAutoUtf8;
+///
+/// let s1: TinyStrAutoUtf8 = "Testing".into();
+/// assert!(matches!(s1, TinyStrAutoUtf8::Tiny { .. }));
+/// assert_eq!(s1, "Testing");
+///
+/// let s2: TinyStrAutoUtf8 = "Zürich".into();
+/// assert!(matches!(s2, TinyStrAutoUtf8::Heap { .. }));
+/// assert_eq!(s2.to_ascii_uppercase(), "ZüRICH");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TinyStrAutoUtf8 {
+    /// Up to 16 ASCII characters stored on the stack.
+    Tiny(TinyStr16),
+    /// Empty, non-ASCII or longer strings stored on the heap.
+    Heap(String),
+}
+
+impl TinyStrAutoUtf8 {
+    /// Extracts a string slice containing the entire `TinyStrAutoUtf8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStrAutoUtf8;
+    ///
+    /// let s1 = TinyStrAutoUtf8::from("日本");
+    ///
+    /// assert_eq!(s1.as_str(), "日本");
+    /// ```
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.deref()
+    }
+
+    /// Checks if the value is composed of ASCII characters only.
+    ///
+    /// This is always `true` for the `Tiny` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStrAutoUtf8;
+    ///
+    /// assert!(TinyStrAutoUtf8::from("Test").is_ascii());
+    /// assert!(!TinyStrAutoUtf8::from("Tést").is_ascii());
+    /// ```
+    pub fn is_ascii(&self) -> bool {
+        match self {
+            TinyStrAutoUtf8::Tiny(_) => true,
+            TinyStrAutoUtf8::Heap(value) => value.is_ascii(),
+        }
+    }
+
+    /// Checks if the value is composed of ASCII alphabetic characters:
+    ///
+    ///  * U+0041 'A' ..= U+005A 'Z', or
+    ///  * U+0061 'a' ..= U+007A 'z'.
+    ///
+    /// Any non-ASCII character, including non-ASCII letters, makes this return `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStrAutoUtf8;
+    ///
+    /// assert!(TinyStrAutoUtf8::from("Test").is_ascii_alphabetic());
+    /// assert!(!TinyStrAutoUtf8::from("Tést").is_ascii_alphabetic());
+    /// ```
+    pub fn is_ascii_alphabetic(&self) -> bool {
+        match self {
+            TinyStrAutoUtf8::Tiny(value) => value.is_ascii_alphabetic(),
+            TinyStrAutoUtf8::Heap(value) => {
+                !value.is_empty() && value.bytes().all(|b| b.is_ascii_alphabetic())
+            }
+        }
+    }
+
+    /// Checks if the value is composed of ASCII alphanumeric characters:
+    ///
+    ///  * U+0041 'A' ..= U+005A 'Z', or
+    ///  * U+0061 'a' ..= U+007A 'z', or
+    ///  * U+0030 '0' ..= U+0039 '9'.
+    ///
+    /// Any non-ASCII character makes this return `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStrAutoUtf8;
+    ///
+    /// assert!(TinyStrAutoUtf8::from("A15b").is_ascii_alphanumeric());
+    /// assert!(!TinyStrAutoUtf8::from("A15ß").is_ascii_alphanumeric());
+    /// ```
+    pub fn is_ascii_alphanumeric(&self) -> bool {
+        match self {
+            TinyStrAutoUtf8::Tiny(value) => value.is_ascii_alphanumeric(),
+            TinyStrAutoUtf8::Heap(value) => {
+                !value.is_empty() && value.bytes().all(|b| b.is_ascii_alphanumeric())
+            }
+        }
+    }
+
+    /// Checks if the value is composed of ASCII decimal digits:
+    ///
+    ///  * U+0030 '0' ..= U+0039 '9'.
+    ///
+    /// Any non-ASCII character, including non-ASCII digits, makes this return `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStrAutoUtf8;
+    ///
+    /// assert!(TinyStrAutoUtf8::from("312").is_ascii_numeric());
+    /// assert!(!TinyStrAutoUtf8::from("٣١٢").is_ascii_numeric());
+    /// ```
+    pub fn is_ascii_numeric(&self) -> bool {
+        match self {
+            TinyStrAutoUtf8::Tiny(value) => value.is_ascii_numeric(),
+            TinyStrAutoUtf8::Heap(value) => {
+                !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+            }
+        }
+    }
+
+    /// Converts this type to its ASCII lower case equivalent.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', other characters,
+    /// including non-ASCII letters, are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStrAutoUtf8;
+    ///
+    /// assert_eq!(TinyStrAutoUtf8::from("TeS3").to_ascii_lowercase(), "tes3");
+    /// assert_eq!(TinyStrAutoUtf8::from("ÉTÉ").to_ascii_lowercase(), "ÉtÉ");
+    /// ```
+    pub fn to_ascii_lowercase(&self) -> Self {
+        match self {
+            TinyStrAutoUtf8::Tiny(value) => TinyStrAutoUtf8::Tiny(value.to_ascii_lowercase()),
+            TinyStrAutoUtf8::Heap(value) => TinyStrAutoUtf8::Heap(value.to_ascii_lowercase()),
+        }
+    }
+
+    /// Converts this type to its ASCII title case equivalent.
+    ///
+    /// First character, if is an ASCII letter 'a' to 'z' is mapped to 'A' to 'Z',
+    /// the remaining ASCII letters are mapped to lower case, and all other characters,
+    /// including non-ASCII letters, are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStrAutoUtf8;
+    ///
+    /// assert_eq!(TinyStrAutoUtf8::from("tEST").to_ascii_titlecase(), "Test");
+    /// assert_eq!(TinyStrAutoUtf8::from("éTÉ").to_ascii_titlecase(), "étÉ");
+    /// ```
+    pub fn to_ascii_titlecase(&self) -> Self {
+        match self {
+            TinyStrAutoUtf8::Tiny(value) => TinyStrAutoUtf8::Tiny(value.to_ascii_titlecase()),
+            TinyStrAutoUtf8::Heap(value) => {
+                let mut result = value.to_ascii_lowercase();
+                if let Some(first) = result.get_mut(..1) {
+                    first.make_ascii_uppercase();
+                }
+                TinyStrAutoUtf8::Heap(result)
+            }
+        }
+    }
+
+    /// Converts this type to its ASCII upper case equivalent.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', other characters,
+    /// including non-ASCII letters, are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyStrAutoUtf8;
+    ///
+    /// assert_eq!(TinyStrAutoUtf8::from("Tes3").to_ascii_uppercase(), "TES3");
+    /// assert_eq!(TinyStrAutoUtf8::from("été").to_ascii_uppercase(), "éTé");
+    /// ```
+    pub fn to_ascii_uppercase(&self) -> Self {
+        match self {
+            TinyStrAutoUtf8::Tiny(value) => TinyStrAutoUtf8::Tiny(value.to_ascii_uppercase()),
+            TinyStrAutoUtf8::Heap(value) => TinyStrAutoUtf8::Heap(value.to_ascii_uppercase()),
+        }
+    }
+}
+
+impl fmt::Display for TinyStrAutoUtf8 {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl Deref for TinyStrAutoUtf8 {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        use TinyStrAutoUtf8::*;
+        match self {
+            Tiny(value) => value.deref(),
+            Heap(value) => value.deref(),
+        }
+    }
+}
+
+impl PartialEq<&str> for TinyStrAutoUtf8 {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.deref() == *other
+    }
+}
+
+impl PartialOrd for TinyStrAutoUtf8 {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TinyStrAutoUtf8 {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl From<&str> for TinyStrAutoUtf8 {
+    fn from(text: &str) -> Self {
+        match TinyStr16::from_str(text) {
+            Ok(tiny) => TinyStrAutoUtf8::Tiny(tiny),
+            Err(_) => TinyStrAutoUtf8::Heap(text.into()),
+        }
+    }
+}
+
+impl From<String> for TinyStrAutoUtf8 {
+    fn from(text: String) -> Self {
+        match TinyStr16::from_str(&text) {
+            Ok(tiny) => TinyStrAutoUtf8::Tiny(tiny),
+            Err(_) => TinyStrAutoUtf8::Heap(text),
+        }
+    }
+}
+
+impl From<TinyStr16> for TinyStrAutoUtf8 {
+    fn from(tiny: TinyStr16) -> Self {
+        TinyStrAutoUtf8::Tiny(tiny)
+    }
+}
+
+impl From<TinyStrAuto> for TinyStrAutoUtf8 {
+    fn from(auto: TinyStrAuto) -> Self {
+        match auto {
+            TinyStrAuto::Tiny(tiny) => TinyStrAutoUtf8::Tiny(tiny),
+            TinyStrAuto::Heap(string) => TinyStrAutoUtf8::Heap(string),
+        }
+    }
+}
+
+impl FromStr for TinyStrAutoUtf8 {
+    type Err = Infallible;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(text.into())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TinyStrAutoUtf8 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TinyStrAutoUtf8 {
+    fn deserialize<D>(deserializer: D) -> Result<TinyStrAutoUtf8, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use alloc::borrow::Cow;
+
+        let x: Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(match x {
+            Cow::Borrowed(text) => text.into(),
+            Cow::Owned(text) => text.into(),
+        })
+    }
+}
@@ -0,0 +1,282 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::Hash;
+use std::num::{NonZeroU128, NonZeroU32, NonZeroU64};
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+/// Marker type selecting the inline storage of a [`TinyUtf8Str`] with capacity `N`.
+///
+/// Only the capacities implementing [`Utf8Storage`] (4, 8 and 16 bytes) can be used.
+pub struct Utf8Capacity<const N: usize>;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Implemented by the [`Utf8Capacity`] markers supported by [`TinyUtf8Str`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Utf8Storage: private::Sealed {
+    #[doc(hidden)]
+    type Word: Copy + Eq + Hash;
+
+    #[doc(hidden)]
+    fn try_from_str(text: &str) -> Result<Self::Word, Error>;
+
+    #[doc(hidden)]
+    fn len(word: Self::Word) -> usize;
+
+    #[doc(hidden)]
+    fn is_ascii(word: Self::Word) -> bool;
+}
+
+/// A tiny string that is from 1 to `N` bytes of non-NUL UTF-8, stored inline.
+///
+/// `TinyUtf8Str` uses the same NUL-padded integer encoding as the ASCII-only types,
+/// so it is `Copy`, the size of its backing integer, and `Option<TinyUtf8Str<N>>` takes
+/// no extra space. `N` is a number of bytes, not characters, and can be 4, 8 or 16.
+///
+/// # Examples
+///
+/// ```
+/// use tinystr::TinyUtf8Str;
+///
+/// let s1: TinyUtf8Str<8> = "日本".parse()
+///     .expect("Failed to parse.");
+///
+/// assert_eq!(s1, "日本");
+/// assert_eq!(s1.len(), 6);
+/// assert!(!s1.is_ascii());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct TinyUtf8Str<const N: usize>(<Utf8Capacity<N> as Utf8Storage>::Word)
+where
+    Utf8Capacity<N>: Utf8Storage;
+
+impl<const N: usize> TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    /// Extracts a string slice containing the entire `TinyUtf8Str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinystr::TinyUtf8Str;
+    ///
+    /// let s1: TinyUtf8Str<8> = "été".parse()
+    ///     .expect("Failed to parse.");
+    ///
+    /// assert_eq!(s1.as_str(), "été");
+    /// ```
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.deref()
+    }
+
+    /// Checks if the value is composed of ASCII characters only.
+    ///
+    /// This is a single mask operation on the backing integer. When it returns `true`,
+    /// the value can be converted into the ASCII-only type of the same size with
+    /// `TryFrom`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use tinystr::{TinyStr8, TinyUtf8Str};
+    ///
+    /// let s1: TinyUtf8Str<8> = "Testing".parse()
+    ///     .expect("Failed to parse.");
+    /// let s2: TinyUtf8Str<8> = "Tésting".parse()
+    ///     .expect("Failed to parse.");
+    ///
+    /// assert!(s1.is_ascii());
+    /// assert!(!s2.is_ascii());
+    /// assert_eq!(TinyStr8::try_from(s1).unwrap(), "Testing");
+    /// ```
+    #[inline(always)]
+    pub fn is_ascii(&self) -> bool {
+        <Utf8Capacity<N> as Utf8Storage>::is_ascii(self.0)
+    }
+}
+
+impl<const N: usize> fmt::Display for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.deref())
+    }
+}
+
+impl<const N: usize> Deref for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        let len = <Utf8Capacity<N> as Utf8Storage>::len(self.0);
+        unsafe {
+            let slice = core::slice::from_raw_parts(&self.0 as *const _ as *const u8, len);
+            std::str::from_utf8_unchecked(slice)
+        }
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.deref() == *other
+    }
+}
+
+impl<const N: usize> PartialOrd for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<const N: usize> FromStr for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    type Err = Error;
+
+    #[inline(always)]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        <Utf8Capacity<N> as Utf8Storage>::try_from_str(text).map(Self)
+    }
+}
+
+macro_rules! impl_utf8_storage {
+    ($size:literal, $tiny:ident, $nonzero:ident, $int:ident) => {
+        impl private::Sealed for Utf8Capacity<$size> {}
+
+        impl Utf8Storage for Utf8Capacity<$size> {
+            type Word = $nonzero;
+
+            #[inline(always)]
+            fn try_from_str(text: &str) -> Result<$nonzero, Error> {
+                let bytes = text.as_bytes();
+                if !(1..=$size).contains(&bytes.len()) {
+                    return Err(Error::invalid_size(bytes.len(), $size));
+                }
+                // A NUL byte can only come from U+0000 in valid UTF-8, and it would
+                // be mistaken for padding.
+                if let Some(index) = bytes.iter().position(|&b| b == 0) {
+                    return Err(Error::InteriorNull { index });
+                }
+                let mut word = [0; $size];
+                word[..bytes.len()].copy_from_slice(bytes);
+                Ok(unsafe { $nonzero::new_unchecked($int::from_ne_bytes(word)) })
+            }
+
+            #[inline(always)]
+            fn len(word: $nonzero) -> usize {
+                let padding = if cfg!(target_endian = "little") {
+                    word.get().leading_zeros()
+                } else {
+                    word.get().trailing_zeros()
+                };
+                ($size - padding / 8) as usize
+            }
+
+            #[inline(always)]
+            fn is_ascii(word: $nonzero) -> bool {
+                word.get() & (<$int>::MAX / 0xff * 0x80) == 0
+            }
+        }
+
+        impl From<$tiny> for TinyUtf8Str<$size> {
+            #[inline(always)]
+            fn from(tiny: $tiny) -> Self {
+                // ASCII-only strings use the same encoding.
+                Self(unsafe { $nonzero::new_unchecked(tiny.as_unsigned()) })
+            }
+        }
+
+        impl TryFrom<TinyUtf8Str<$size>> for $tiny {
+            type Error = Error;
+
+            #[inline(always)]
+            fn try_from(utf8: TinyUtf8Str<$size>) -> Result<Self, Self::Error> {
+                if utf8.is_ascii() {
+                    Ok(unsafe { $tiny::from_native_unchecked(utf8.0.get()) })
+                } else {
+                    Err(Error::invalid_byte(utf8.as_bytes(), 0))
+                }
+            }
+        }
+    };
+}
+
+impl_utf8_storage!(4, TinyStr4, NonZeroU32, u32);
+impl_utf8_storage!(8, TinyStr8, NonZeroU64, u64);
+impl_utf8_storage!(16, TinyStr16, NonZeroU128, u128);
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for TinyUtf8Str<N>
+where
+    Utf8Capacity<N>: Utf8Storage,
+{
+    fn deserialize<D>(deserializer: D) -> Result<TinyUtf8Str<N>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use alloc::borrow::Cow;
+        use alloc::string::ToString;
+        use serde::de::Error as SerdeError;
+
+        let x: Cow<'de, str> = serde::Deserialize::deserialize(deserializer)?;
+        x.parse()
+            .map_err(|e: Error| SerdeError::custom(e.to_string()))
+    }
+}
@@ -5,7 +5,8 @@
 //! This module contains adapters to allow `tinystr` to work with [`zerovec`](https://docs.rs/zerovec)
 //! and is enabled by enabling the `"zerovec"` feature of the `tinystr` crate.
 
-use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+use crate::{Error, TinyBytes, TinyStr16, TinyStr4, TinyStr8};
+use std::convert::TryFrom;
 use std::mem;
 use zerovec::ule::{AsULE, PlainOldULE, ULE};
 
@@ -65,14 +66,17 @@ macro_rules! impl_str_ule_size {
             fn validate_byte_slice(bytes: &[u8]) -> Result<(), Self::Error> {
                 debug_assert!(mem::size_of::<$tiny>() == mem::size_of::<[u8; $size]>());
 
-                let data = bytes.as_ptr();
-                let len = bytes.len() / $size;
-
-                let bytes_slice: &[[u8; $size]] =
-                    unsafe { std::slice::from_raw_parts(data as *const [u8; $size], len) };
-                for bytes in bytes_slice {
-                    let bytes = bytes.split(|t| *t == 0).next().ok_or(Error::InvalidNull)?;
-                    let _ = <$tiny>::from_bytes(&*bytes)?;
+                let chunks = bytes.chunks_exact($size);
+                if !chunks.remainder().is_empty() {
+                    return Err(Error::InvalidSliceLength {
+                        len: bytes.len(),
+                        size: $size,
+                    });
+                }
+                for chunk in chunks {
+                    // Cannot fail, since every chunk has exactly `$size` bytes.
+                    let chunk = <&[u8; $size]>::try_from(chunk).unwrap();
+                    let _ = <$tiny>::from_padded_bytes(chunk)?;
                 }
                 Ok(())
             }
@@ -84,15 +88,62 @@ impl_str_ule_size!(4, TinyStr4, u32);
 impl_str_ule_size!(8, TinyStr8, u64);
 impl_str_ule_size!(16, TinyStr16, u128);
 
+/// `TinyBytes` has no alignment requirement and no endianness, so it is its own ULE.
+///
+/// This impl is made available by enabling the `"zerovec"` feature of the `tinystr` crate.
+unsafe impl<const N: usize> ULE for TinyBytes<N> {
+    type Error = Error;
+    #[inline]
+    fn validate_byte_slice(bytes: &[u8]) -> Result<(), Self::Error> {
+        // N data bytes followed by the length byte.
+        let size = mem::size_of::<Self>();
+        debug_assert!(size == N + 1);
+        let chunks = bytes.chunks_exact(size);
+        if !chunks.remainder().is_empty() {
+            return Err(Error::InvalidSliceLength {
+                len: bytes.len(),
+                size,
+            });
+        }
+        for chunk in chunks {
+            let (data, len) = chunk.split_at(N);
+            let len = len[0] as usize;
+            if len > N {
+                return Err(Error::TooLong { len, max: N });
+            }
+            // Only NUL is allowed past the length.
+            if let Some(i) = data[len..].iter().position(|b| *b != 0) {
+                return Err(Error::InvalidPadding {
+                    index: len + i,
+                    byte: data[len + i],
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// This impl is made available by enabling the `"zerovec"` feature of the `tinystr` crate.
+impl<const N: usize> AsULE for TinyBytes<N> {
+    type ULE = Self;
+    #[inline]
+    fn as_unaligned(self) -> Self::ULE {
+        self
+    }
+    #[inline]
+    fn from_unaligned(unaligned: Self::ULE) -> Self {
+        unaligned
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::vec;
     use std::vec::Vec;
 
     #[test]
     fn test_roundtrip() {
-        let strings = vec!["en", "us", "zh-CN"];
+        let strings = ["en", "us", "zh-CN"];
         let tinies: Vec<TinyStr8> = strings.iter().map(|s| s.parse().unwrap()).collect();
         let individually_converted: Vec<AsciiULE<8>> =
             tinies.iter().map(|s| s.as_unaligned()).collect();
@@ -106,4 +157,57 @@ mod tests {
             .collect();
         assert_eq!(tinies, recouped_tinies);
     }
+
+    #[test]
+    fn test_validate_errors() {
+        assert_eq!(
+            AsciiULE::<4>::parse_byte_slice(b"en\0\0us\0\0").map(|s| s.len()),
+            Ok(2)
+        );
+        assert_eq!(
+            AsciiULE::<4>::parse_byte_slice(b"en\0\0us"),
+            Err(Error::InvalidSliceLength { len: 6, size: 4 })
+        );
+        assert_eq!(
+            AsciiULE::<4>::parse_byte_slice(b"\0\0\0\0"),
+            Err(Error::Empty)
+        );
+        assert_eq!(
+            AsciiULE::<8>::parse_byte_slice(b"en\0us\0\0\0"),
+            Err(Error::InteriorNull { index: 2 })
+        );
+        assert_eq!(
+            AsciiULE::<16>::parse_byte_slice(b"caf\xc3\xa9\0\0\0\0\0\0\0\0\0\0\0"),
+            Err(Error::NonAscii {
+                index: 3,
+                byte: 0xc3
+            })
+        );
+    }
+
+    #[test]
+    fn test_tinybytes_roundtrip() {
+        let values = [&b"\0asm"[..], b"", b"\xff\x00\x01"];
+        let tinies: Vec<TinyBytes<4>> = values
+            .iter()
+            .map(|b| TinyBytes::from_bytes(b).unwrap())
+            .collect();
+        let slice = TinyBytes::as_byte_slice(&tinies);
+        assert_eq!(slice.len(), 15);
+        let parsed = TinyBytes::<4>::parse_byte_slice(slice).expect("Slice must parse");
+        assert_eq!(&tinies[..], parsed);
+
+        assert_eq!(
+            TinyBytes::<4>::parse_byte_slice(&[1, 2, 3, 4, 5]),
+            Err(Error::TooLong { len: 5, max: 4 })
+        );
+        assert_eq!(
+            TinyBytes::<4>::parse_byte_slice(&[1, 2, 3, 4, 0, 1]),
+            Err(Error::InvalidSliceLength { len: 6, size: 5 })
+        );
+        assert_eq!(
+            TinyBytes::<4>::parse_byte_slice(&[1, 2, 3, 4, 2]),
+            Err(Error::InvalidPadding { index: 2, byte: 3 })
+        );
+    }
 }
@@ -0,0 +1,92 @@
+use std::convert::TryFrom;
+
+use crate::swar::{w128, w32, w64};
+use crate::{Error, TinyStr16, TinyStr4, TinyStr8};
+
+macro_rules! impl_try_from_unsigned {
+    ($ty:ident, $int:ident, $swar:ident, $example:literal) => {
+        impl $ty {
+            #[doc = concat!("Creates a ", stringify!($ty), " from a little-endian integer, such as one returned by")]
+            #[doc = concat!("`Into<", stringify!($int), ">`, checking that it is a valid encoding.")]
+            ///
+            /// The integer must be non-zero, with ASCII bytes, and with no NUL byte before
+            /// the last non-NUL byte.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::{Error, ", stringify!($ty), "};")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            #[doc = concat!("let num: ", stringify!($int), " = s1.into();")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($ty), "::try_from_le_unsigned(num), Ok(s1));")]
+            #[doc = concat!("assert_eq!(", stringify!($ty), "::try_from_le_unsigned(0), Err(Error::Empty));")]
+            /// assert_eq!(
+            #[doc = concat!("    ", stringify!($ty), "::try_from_le_unsigned(0x6100),")]
+            ///     Err(Error::InteriorNull { index: 0 })
+            /// );
+            /// ```
+            #[inline]
+            pub const fn try_from_le_unsigned(word: $int) -> Result<Self, Error> {
+                if word == 0 {
+                    return Err(Error::Empty);
+                }
+                let high = word & $swar::HIGH;
+                if high != 0 {
+                    let index = (high.trailing_zeros() / 8) as usize;
+                    return Err(Error::NonAscii {
+                        index,
+                        byte: $swar::byte(word, index),
+                    });
+                }
+                // NUL bytes are only allowed as padding after the last non-NUL byte.
+                let nul = $swar::eq_mask(word, 0) & $swar::low_bytes($swar::len(word));
+                if nul != 0 {
+                    return Err(Error::InteriorNull {
+                        index: (nul.trailing_zeros() / 8) as usize,
+                    });
+                }
+                // SAFETY: The word is checked above to be non-zero, ASCII and NUL-padded.
+                Ok(unsafe { Self::from_le_word_unchecked(word) })
+            }
+
+            #[doc = concat!("Creates a ", stringify!($ty), " from a native-endian integer, such as one returned by")]
+            #[doc = concat!("[`", stringify!($ty), "::as_unsigned()`], checking that it is a valid encoding.")]
+            ///
+            #[doc = concat!("See [`", stringify!($ty), "::try_from_le_unsigned()`].")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use tinystr::", stringify!($ty), ";")]
+            ///
+            #[doc = concat!("let s1: ", stringify!($ty), " = \"", $example, "\".parse()")]
+            ///     .expect("Failed to parse.");
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($ty), "::try_from_native_unsigned(s1.as_unsigned()), Ok(s1));")]
+            /// ```
+            #[inline]
+            pub const fn try_from_native_unsigned(word: $int) -> Result<Self, Error> {
+                Self::try_from_le_unsigned(word.to_le())
+            }
+        }
+
+        #[doc = concat!("Checks a little-endian integer, the inverse of `Into<", stringify!($int), ">`.")]
+        ///
+        #[doc = concat!("See [`", stringify!($ty), "::try_from_le_unsigned()`].")]
+        impl TryFrom<$int> for $ty {
+            type Error = Error;
+
+            #[inline]
+            fn try_from(word: $int) -> Result<Self, Self::Error> {
+                Self::try_from_le_unsigned(word)
+            }
+        }
+    };
+}
+
+impl_try_from_unsigned!(TinyStr4, u32, w32, "Test");
+impl_try_from_unsigned!(TinyStr8, u64, w64, "Testing");
+impl_try_from_unsigned!(TinyStr16, u128, w128, "Metamorphosis");
@@ -1,14 +1,21 @@
 use rand::SeedableRng;
 use rand_distr::{Alphanumeric, Distribution, Uniform};
 use rand_pcg::Lcg64Xsh32;
+use std::convert::TryFrom;
 use std::fmt::Write;
 use std::mem::size_of;
 use std::ops::Deref;
 use std::ops::RangeInclusive;
-use tinystr::{tinystr16, tinystr4, tinystr8, Error, TinyStr16, TinyStr4, TinyStr8};
+use std::str::FromStr;
+use tinystr::{
+    define_tinystr_type, exact_tinystr, tinystr16, tinystr4, tinystr8, AsciiCase,
+    AsciiCaseInsensitive, AsciiClass, Error, ExactTinyStr, TinyBytes, TinyPattern, TinyShape,
+    TinyStr16, TinyStr16OrEmpty, TinyStr4, TinyStr4OrEmpty, TinyStr8, TinyStr8OrEmpty,
+    TinyStrBuilder, TinyUtf8Str,
+};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
-use tinystr::TinyStrAuto;
+use tinystr::{TinyStrAuto, TinyStrAutoUtf8};
 
 /// Generates an array of random alphanumeric strings.
 ///
@@ -33,11 +40,293 @@ fn random_alphanums(lengths: RangeInclusive<usize>, count: usize) -> Vec<String>
         .collect()
 }
 
+macro_rules! test_parse_errors {
+    ($name:ident, $ty:ident, $or_empty:ident, $max:literal) => {
+        #[test]
+        fn $name() {
+            let long = "abcdefghijklmnopq";
+            assert_eq!("".parse::<$ty>(), Err(Error::Empty));
+            assert_eq!($ty::from_bytes(&[]), Err(Error::Empty));
+            assert_eq!("".parse::<$or_empty>(), Ok($or_empty::EMPTY));
+            assert_eq!(
+                long[..$max + 1].parse::<$ty>(),
+                Err(Error::TooLong {
+                    len: $max + 1,
+                    max: $max
+                })
+            );
+            assert_eq!(
+                long[..$max + 1].parse::<$or_empty>(),
+                Err(Error::TooLong {
+                    len: $max + 1,
+                    max: $max
+                })
+            );
+            assert_eq!("\0".parse::<$ty>(), Err(Error::InteriorNull { index: 0 }));
+            assert_eq!(
+                $ty::from_bytes(&long.as_bytes()[..$max - 1])
+                    .and_then(|t| t.concat_with_separator(0, t)),
+                Err(Error::InteriorNull { index: $max - 1 })
+            );
+            assert_eq!(
+                $ty::from_bytes(b"ab\0"),
+                Err(Error::InteriorNull { index: 2 })
+            );
+            assert_eq!(
+                $ty::from_bytes(b"a\0\x80"),
+                Err(Error::NonAscii {
+                    index: 2,
+                    byte: 0x80
+                })
+            );
+        }
+    };
+}
+
+test_parse_errors!(tiny4_parse_errors, TinyStr4, TinyStr4OrEmpty, 4);
+test_parse_errors!(tiny8_parse_errors, TinyStr8, TinyStr8OrEmpty, 8);
+test_parse_errors!(tiny16_parse_errors, TinyStr16, TinyStr16OrEmpty, 16);
+
+macro_rules! test_padded {
+    ($f:ident, $ty:ident, $or_empty:ident, $max:literal) => {
+        #[test]
+        fn $f() {
+            for string in random_alphanums(1..=$max, 100) {
+                let tiny: $ty = string.parse().unwrap();
+                let mut padded = [0; $max];
+                padded[..string.len()].copy_from_slice(string.as_bytes());
+                assert_eq!(tiny.to_padded_bytes(), padded);
+                assert_eq!($ty::from_padded_bytes(&padded), Ok(tiny));
+                assert_eq!(
+                    $or_empty::from_padded_bytes(&padded),
+                    Ok($or_empty::from(tiny))
+                );
+                assert_eq!($or_empty::from(tiny).to_padded_bytes(), padded);
+            }
+
+            let mut padded = [0; $max];
+            assert_eq!($ty::from_padded_bytes(&padded), Err(Error::Empty));
+            assert_eq!($or_empty::from_padded_bytes(&padded), Ok($or_empty::EMPTY));
+            assert_eq!($or_empty::EMPTY.to_padded_bytes(), padded);
+            padded[$max - 1] = b'a';
+            assert_eq!(
+                $ty::from_padded_bytes(&padded),
+                Err(Error::InteriorNull { index: 0 })
+            );
+            assert_eq!(
+                $or_empty::from_padded_bytes(&padded),
+                Err(Error::InteriorNull { index: 0 })
+            );
+            padded[0] = b'a';
+            padded[1] = 0;
+            assert_eq!(
+                $ty::from_padded_bytes(&padded),
+                Err(Error::InteriorNull { index: 1 })
+            );
+            padded[1] = 0x80;
+            assert_eq!(
+                $ty::from_padded_bytes(&padded),
+                Err(Error::NonAscii {
+                    index: 1,
+                    byte: 0x80
+                })
+            );
+        }
+    };
+}
+
+test_padded!(tiny4_padded, TinyStr4, TinyStr4OrEmpty, 4);
+test_padded!(tiny8_padded, TinyStr8, TinyStr8OrEmpty, 8);
+test_padded!(tiny16_padded, TinyStr16, TinyStr16OrEmpty, 16);
+
+macro_rules! test_try_from_unsigned {
+    ($f:ident, $ty:ident, $int:ident, $max:literal) => {
+        /// Test consistency of the integer constructors with from_padded_bytes
+        #[test]
+        fn $f() {
+            for string in random_alphanums(1..=$max, 100) {
+                let tiny: $ty = string.parse().unwrap();
+                let le: $int = tiny.into();
+                assert_eq!($ty::try_from(le), Ok(tiny));
+                assert_eq!($ty::try_from_le_unsigned(le), Ok(tiny));
+                assert_eq!($ty::try_from_native_unsigned(tiny.as_unsigned()), Ok(tiny));
+            }
+
+            // Words made of a few interesting bytes.
+            let mut rng = Lcg64Xsh32::seed_from_u64(2021);
+            let byte_dist = Uniform::from(0..6);
+            let bytes = [0, 0, b'a', 0x7f, 0x80, 0xff];
+            for _ in 0..1000 {
+                let mut padded = [0; $max];
+                for byte in padded.iter_mut() {
+                    *byte = bytes[byte_dist.sample(&mut rng)];
+                }
+                let le = $int::from_le_bytes(padded);
+                let expected = $ty::from_padded_bytes(&padded);
+                assert_eq!($ty::try_from(le), expected, "{:?}", padded);
+                assert_eq!(
+                    $ty::try_from_native_unsigned($int::from_ne_bytes(padded)),
+                    expected
+                );
+            }
+            assert_eq!($ty::try_from(0), Err(Error::Empty));
+            assert_eq!($ty::try_from(0x6100), Err(Error::InteriorNull { index: 0 }));
+            assert_eq!(
+                $ty::try_from(0x8061),
+                Err(Error::NonAscii {
+                    index: 1,
+                    byte: 0x80
+                })
+            );
+        }
+    };
+}
+
+test_try_from_unsigned!(tiny4_try_from_unsigned, TinyStr4, u32, 4);
+test_try_from_unsigned!(tiny8_try_from_unsigned, TinyStr8, u64, 8);
+test_try_from_unsigned!(tiny16_try_from_unsigned, TinyStr16, u128, 16);
+
+macro_rules! test_sort_key {
+    ($f:ident, $ty:ident, $int:ident, $max:literal) => {
+        #[test]
+        fn $f() {
+            let mut strings: Vec<$ty> = random_alphanums(1..=$max, 2000)
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect();
+            // Add duplicates and strings that only differ in length.
+            strings.extend_from_within(..500);
+            strings.extend(
+                ["a", "aa", "a", "Z", "0"]
+                    .iter()
+                    .map(|s| s.parse::<$ty>().unwrap()),
+            );
+
+            for pair in strings.windows(2) {
+                assert_eq!(
+                    pair[0].to_sort_key().cmp(&pair[1].to_sort_key()),
+                    pair[0].as_str().cmp(pair[1].as_str())
+                );
+                assert_eq!(
+                    pair[0].to_be_bytes().cmp(&pair[1].to_be_bytes()),
+                    pair[0].as_str().cmp(pair[1].as_str())
+                );
+            }
+            for &tiny in strings.iter() {
+                assert_eq!($ty::from_sort_key(tiny.to_sort_key()), Ok(tiny));
+                assert_eq!($ty::from_be_bytes(tiny.to_be_bytes()), Ok(tiny));
+                assert_eq!(tiny.to_be_bytes(), tiny.to_padded_bytes());
+            }
+
+            let mut expected = strings.clone();
+            expected.sort_unstable();
+            $ty::radix_sort(&mut strings);
+            assert_eq!(strings, expected);
+
+            let mut small = expected[..10].to_vec();
+            small.reverse();
+            $ty::radix_sort(&mut small);
+            assert_eq!(small, &expected[..10]);
+            $ty::radix_sort(&mut []);
+
+            assert_eq!($ty::from_sort_key(0), Err(Error::Empty));
+            assert_eq!(
+                $ty::from_sort_key(0x61 as $int),
+                Err(Error::InteriorNull { index: 0 })
+            );
+            assert!($ty::from_be_bytes([0x80; $max]).is_err());
+        }
+    };
+}
+
+test_sort_key!(tiny4_sort_key, TinyStr4, u32, 4);
+test_sort_key!(tiny8_sort_key, TinyStr8, u64, 8);
+test_sort_key!(tiny16_sort_key, TinyStr16, u128, 16);
+
+#[test]
+fn tiny_width_conversions() {
+    for string in random_alphanums(1..=16, 100) {
+        let tiny16: TinyStr16 = string.parse().unwrap();
+        let tiny8 = TinyStr8::try_from(tiny16);
+        let tiny4 = TinyStr4::try_from(tiny16);
+        assert_eq!(tiny8, string.parse::<TinyStr8>());
+        assert_eq!(tiny4, string.parse::<TinyStr4>());
+        if let Ok(tiny8) = tiny8 {
+            assert_eq!(TinyStr16::from(tiny8), tiny16);
+            assert_eq!(TinyStr4::try_from(tiny8), tiny4);
+            assert_eq!(tiny8, tiny16);
+            assert_eq!(tiny16, tiny8);
+        }
+        if let Ok(tiny4) = tiny4 {
+            assert_eq!(TinyStr16::from(tiny4), tiny16);
+            assert_eq!(TinyStr8::from(tiny4), tiny8.unwrap());
+            assert_eq!(tiny4, tiny16);
+            assert_eq!(tiny8.unwrap(), tiny4);
+        }
+    }
+    assert_ne!(tinystr4!("abc"), tinystr8!("abcd"));
+    assert_ne!(tinystr16!("abcdefgh"), tinystr8!("abcdefg"));
+    assert_eq!(
+        TinyStr4::try_from(tinystr8!("abcdefg")),
+        Err(Error::TooLong { len: 7, max: 4 })
+    );
+    assert_eq!(
+        TinyStr8::try_from(tinystr16!("metamorphosis")),
+        Err(Error::TooLong { len: 13, max: 8 })
+    );
+}
+
+#[test]
+fn tiny_std_conversions() {
+    assert_eq!(TinyStr4::try_from("en"), Ok(tinystr4!("en")));
+    assert_eq!(TinyStr8::try_from(&b"en-US"[..]), Ok(tinystr8!("en-US")));
+    assert_eq!(
+        TinyStr16::try_from("a\0b"),
+        Err(Error::InteriorNull { index: 1 })
+    );
+    assert_eq!(TinyStr4::try_from(&b""[..]), Err(Error::Empty));
+
+    let tiny = tinystr8!("en-US");
+    assert_eq!(tiny, "en-US");
+    assert_eq!(tiny, *"en-US");
+    assert_eq!("en-US", tiny);
+    assert_eq!(*"en-US", tiny);
+    assert_ne!("en", tiny);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn tiny_string_conversions() {
+    assert_eq!(
+        TinyStr16::try_from(String::from("metamorphosis")),
+        Ok(tinystr16!("metamorphosis"))
+    );
+    assert_eq!(
+        TinyStr4::try_from(String::from("Latin")),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+    assert_eq!(String::from(tinystr4!("Latn")), "Latn");
+    assert_eq!(&*Box::<str>::from(tinystr8!("calendar")), "calendar");
+
+    let string = String::from("zh-Hant");
+    assert_eq!(tinystr8!("zh-Hant"), string);
+    assert_eq!(string, tinystr8!("zh-Hant"));
+    assert_ne!(string, tinystr16!("zh-Hans"));
+}
+
 #[test]
 fn tiny_sizes() {
     assert_eq!(4, size_of::<TinyStr4>());
     assert_eq!(8, size_of::<TinyStr8>());
     assert_eq!(16, size_of::<TinyStr16>());
+    assert_eq!(4, size_of::<TinyUtf8Str<4>>());
+    assert_eq!(8, size_of::<TinyUtf8Str<8>>());
+    assert_eq!(16, size_of::<TinyUtf8Str<16>>());
+    assert_eq!(8, size_of::<Option<TinyUtf8Str<8>>>());
+    assert_eq!(5, size_of::<TinyBytes<4>>());
+    assert_eq!(4, size_of::<TinyStr4OrEmpty>());
+    assert_eq!(8, size_of::<TinyStr8OrEmpty>());
+    assert_eq!(16, size_of::<TinyStr16OrEmpty>());
     #[cfg(target_pointer_width = "64")]
     assert_eq!(24, size_of::<String>());
     // Note: TinyStrAuto is size 32 even when a smaller TinyStr type is used
@@ -58,26 +347,41 @@ fn tiny4_from_bytes() {
 
     assert_eq!(
         TinyStr4::from_bytes(&[0, 159, 146, 150]),
-        Err(Error::NonAscii)
+        Err(Error::NonAscii {
+            index: 1,
+            byte: 159
+        })
+    );
+    assert_eq!(TinyStr4::from_bytes(&[]), Err(Error::Empty));
+    assert_eq!(
+        TinyStr4::from_bytes(&[0]),
+        Err(Error::InteriorNull { index: 0 })
     );
-    assert_eq!(TinyStr4::from_bytes(&[]), Err(Error::InvalidSize));
-    assert_eq!(TinyStr4::from_bytes(&[0]), Err(Error::InvalidNull));
 }
 
 #[test]
 fn tiny4_size() {
-    assert_eq!("".parse::<TinyStr4>(), Err(Error::InvalidSize));
+    assert_eq!("".parse::<TinyStr4>(), Err(Error::Empty));
     assert!("1".parse::<TinyStr4>().is_ok());
     assert!("12".parse::<TinyStr4>().is_ok());
     assert!("123".parse::<TinyStr4>().is_ok());
     assert!("1234".parse::<TinyStr4>().is_ok());
-    assert_eq!("12345".parse::<TinyStr4>(), Err(Error::InvalidSize));
-    assert_eq!("123456789".parse::<TinyStr4>(), Err(Error::InvalidSize));
+    assert_eq!(
+        "12345".parse::<TinyStr4>(),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+    assert_eq!(
+        "123456789".parse::<TinyStr4>(),
+        Err(Error::TooLong { len: 9, max: 4 })
+    );
 }
 
 #[test]
 fn tiny4_null() {
-    assert_eq!("a\u{0}b".parse::<TinyStr4>(), Err(Error::InvalidNull));
+    assert_eq!(
+        "a\u{0}b".parse::<TinyStr4>(),
+        Err(Error::InteriorNull { index: 1 })
+    );
 }
 
 #[test]
@@ -91,7 +395,13 @@ fn tiny4_new_unchecked() {
 
 #[test]
 fn tiny4_nonascii() {
-    assert_eq!("\u{4000}".parse::<TinyStr4>(), Err(Error::NonAscii));
+    assert_eq!(
+        "\u{4000}".parse::<TinyStr4>(),
+        Err(Error::NonAscii {
+            index: 0,
+            byte: 0xe4
+        })
+    );
 }
 
 #[test]
@@ -169,7 +479,7 @@ fn tiny4_ord() {
         tinystr4!("fr"),
     ];
     v.sort();
-    assert_eq!(Some("aab"), v.get(0).map(TinyStr4::as_str));
+    assert_eq!(Some("aab"), v.first().map(TinyStr4::as_str));
     assert_eq!(Some("fr"), v.get(1).map(TinyStr4::as_str));
     assert_eq!(Some("zh"), v.get(2).map(TinyStr4::as_str));
     assert_eq!(Some("zzy"), v.get(3).map(TinyStr4::as_str));
@@ -230,15 +540,21 @@ fn tiny8_from_bytes() {
 
     assert_eq!(
         TinyStr8::from_bytes(&[0, 159, 146, 150]),
-        Err(Error::NonAscii)
+        Err(Error::NonAscii {
+            index: 1,
+            byte: 159
+        })
+    );
+    assert_eq!(TinyStr8::from_bytes(&[]), Err(Error::Empty));
+    assert_eq!(
+        TinyStr8::from_bytes(&[0]),
+        Err(Error::InteriorNull { index: 0 })
     );
-    assert_eq!(TinyStr8::from_bytes(&[]), Err(Error::InvalidSize));
-    assert_eq!(TinyStr8::from_bytes(&[0]), Err(Error::InvalidNull));
 }
 
 #[test]
 fn tiny8_size() {
-    assert_eq!("".parse::<TinyStr8>(), Err(Error::InvalidSize));
+    assert_eq!("".parse::<TinyStr8>(), Err(Error::Empty));
     assert!("1".parse::<TinyStr8>().is_ok());
     assert!("12".parse::<TinyStr8>().is_ok());
     assert!("123".parse::<TinyStr8>().is_ok());
@@ -247,12 +563,18 @@ fn tiny8_size() {
     assert!("123456".parse::<TinyStr8>().is_ok());
     assert!("1234567".parse::<TinyStr8>().is_ok());
     assert!("12345678".parse::<TinyStr8>().is_ok());
-    assert_eq!("123456789".parse::<TinyStr8>(), Err(Error::InvalidSize));
+    assert_eq!(
+        "123456789".parse::<TinyStr8>(),
+        Err(Error::TooLong { len: 9, max: 8 })
+    );
 }
 
 #[test]
 fn tiny8_null() {
-    assert_eq!("a\u{0}b".parse::<TinyStr8>(), Err(Error::InvalidNull));
+    assert_eq!(
+        "a\u{0}b".parse::<TinyStr8>(),
+        Err(Error::InteriorNull { index: 1 })
+    );
 }
 
 #[test]
@@ -266,7 +588,13 @@ fn tiny8_new_unchecked() {
 
 #[test]
 fn tiny8_nonascii() {
-    assert_eq!("\u{4000}".parse::<TinyStr8>(), Err(Error::NonAscii));
+    assert_eq!(
+        "\u{4000}".parse::<TinyStr8>(),
+        Err(Error::NonAscii {
+            index: 0,
+            byte: 0xe4
+        })
+    );
 }
 
 #[test]
@@ -350,7 +678,7 @@ fn tiny8_ord() {
         tinystr8!("aab"),
     ];
     v.sort();
-    assert_eq!(Some("aab"), v.get(0).map(TinyStr8::as_str));
+    assert_eq!(Some("aab"), v.first().map(TinyStr8::as_str));
     assert_eq!(Some("macos"), v.get(1).map(TinyStr8::as_str));
     assert_eq!(Some("nedis"), v.get(2).map(TinyStr8::as_str));
     assert_eq!(Some("zzy"), v.get(3).map(TinyStr8::as_str));
@@ -405,15 +733,21 @@ fn tiny16_from_bytes() {
 
     assert_eq!(
         TinyStr16::from_bytes(&[0, 159, 146, 150]),
-        Err(Error::NonAscii)
+        Err(Error::NonAscii {
+            index: 1,
+            byte: 159
+        })
+    );
+    assert_eq!(TinyStr16::from_bytes(&[]), Err(Error::Empty));
+    assert_eq!(
+        TinyStr16::from_bytes(&[0]),
+        Err(Error::InteriorNull { index: 0 })
     );
-    assert_eq!(TinyStr16::from_bytes(&[]), Err(Error::InvalidSize));
-    assert_eq!(TinyStr16::from_bytes(&[0]), Err(Error::InvalidNull));
 }
 
 #[test]
 fn tiny16_size() {
-    assert_eq!("".parse::<TinyStr16>(), Err(Error::InvalidSize));
+    assert_eq!("".parse::<TinyStr16>(), Err(Error::Empty));
     assert!("1".parse::<TinyStr16>().is_ok());
     assert!("12".parse::<TinyStr16>().is_ok());
     assert!("123".parse::<TinyStr16>().is_ok());
@@ -432,13 +766,16 @@ fn tiny16_size() {
     assert!("1234567812345678".parse::<TinyStr16>().is_ok());
     assert_eq!(
         "12345678123456789".parse::<TinyStr16>(),
-        Err(Error::InvalidSize)
+        Err(Error::TooLong { len: 17, max: 16 })
     );
 }
 
 #[test]
 fn tiny16_null() {
-    assert_eq!("a\u{0}b".parse::<TinyStr16>(), Err(Error::InvalidNull));
+    assert_eq!(
+        "a\u{0}b".parse::<TinyStr16>(),
+        Err(Error::InteriorNull { index: 1 })
+    );
 }
 
 #[test]
@@ -452,7 +789,13 @@ fn tiny16_new_unchecked() {
 
 #[test]
 fn tiny16_nonascii() {
-    assert_eq!("\u{4000}".parse::<TinyStr16>(), Err(Error::NonAscii));
+    assert_eq!(
+        "\u{4000}".parse::<TinyStr16>(),
+        Err(Error::NonAscii {
+            index: 0,
+            byte: 0xe4
+        })
+    );
 }
 
 #[test]
@@ -547,7 +890,7 @@ fn tiny16_ord() {
         tinystr16!("aab"),
     ];
     v.sort();
-    assert_eq!(Some("aab"), v.get(0).map(TinyStr16::as_str));
+    assert_eq!(Some("aab"), v.first().map(TinyStr16::as_str));
     assert_eq!(Some("macos_xxxx"), v.get(1).map(TinyStr16::as_str));
     assert_eq!(Some("nedis_xxxx"), v.get(2).map(TinyStr16::as_str));
     assert_eq!(Some("xxxxxxxx_aa"), v.get(3).map(TinyStr16::as_str));
@@ -597,6 +940,1428 @@ fn tiny16_debug() {
     assert_eq!(format!("{:#?}", s), "\"abcdefghijkl\"");
 }
 
+#[test]
+fn tinyutf8_basic() {
+    let s: TinyUtf8Str<8> = "日本".parse().unwrap();
+    assert_eq!(s.deref(), "日本");
+    assert_eq!(s.as_str(), "日本");
+    assert_eq!(s.chars().count(), 2);
+    assert_eq!(format!("{}", s), "日本");
+    assert_eq!(format!("{:?}", s), "\"日本\"");
+
+    let s: TinyUtf8Str<4> = "abcd".parse().unwrap();
+    assert_eq!(s, "abcd");
+}
+
+#[test]
+fn tinyutf8_size() {
+    assert_eq!("".parse::<TinyUtf8Str<4>>(), Err(Error::Empty));
+    assert!("日".parse::<TinyUtf8Str<4>>().is_ok());
+    assert_eq!(
+        "日本".parse::<TinyUtf8Str<4>>(),
+        Err(Error::TooLong { len: 6, max: 4 })
+    );
+    assert!("日本".parse::<TinyUtf8Str<8>>().is_ok());
+    assert!("日本語です".parse::<TinyUtf8Str<16>>().is_ok());
+    assert_eq!(
+        "日本語ですよ".parse::<TinyUtf8Str<16>>(),
+        Err(Error::TooLong { len: 18, max: 16 })
+    );
+    assert_eq!(
+        "a\u{0}b".parse::<TinyUtf8Str<8>>(),
+        Err(Error::InteriorNull { index: 1 })
+    );
+}
+
+#[test]
+fn tinyutf8_ascii() {
+    let ascii: TinyUtf8Str<16> = "metamorphosis".parse().unwrap();
+    assert!(ascii.is_ascii());
+    assert_eq!(TinyStr16::try_from(ascii), Ok(tinystr16!("metamorphosis")));
+    assert_eq!(TinyUtf8Str::from(tinystr16!("metamorphosis")), ascii);
+
+    let utf8: TinyUtf8Str<4> = "ét".parse().unwrap();
+    assert!(!utf8.is_ascii());
+    assert_eq!(
+        TinyStr4::try_from(utf8),
+        Err(Error::NonAscii {
+            index: 0,
+            byte: 0xc3
+        })
+    );
+
+    let s: TinyUtf8Str<8> = TinyUtf8Str::from(tinystr8!("en-US"));
+    assert_eq!(s, "en-US");
+    assert_eq!(TinyStr8::try_from(s), Ok(tinystr8!("en-US")));
+}
+
+#[test]
+fn tinyutf8_ord() {
+    let mut v: Vec<TinyUtf8Str<8>> = ["日本", "zh", "été", "aab", "zzy"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    v.sort();
+    assert_eq!(
+        v.iter().map(TinyUtf8Str::as_str).collect::<Vec<_>>(),
+        vec!["aab", "zh", "zzy", "été", "日本"]
+    );
+}
+
+#[test]
+fn tinybytes_basic() {
+    let b = TinyBytes::<8>::from_bytes(b"a\0\xff").unwrap();
+    assert_eq!(b.len(), 3);
+    assert_eq!(b.deref(), b"a\0\xff");
+    assert_eq!(format!("{:?}", b), "b\"a\\x00\\xff\"");
+
+    let empty = TinyBytes::<8>::from_bytes(b"").unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty, TinyBytes::default());
+    assert_ne!(empty, b);
+
+    let full = TinyBytes::from([0u8; 4]);
+    assert_eq!(full.deref(), &[0, 0, 0, 0]);
+    assert_ne!(full, TinyBytes::<4>::from_bytes(&[0, 0]).unwrap());
+
+    assert_eq!(
+        TinyBytes::<4>::from_bytes(b"12345"),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+    assert_eq!(
+        TinyBytes::<4>::try_from(&b"1234"[..]),
+        Ok(TinyBytes::from(*b"1234"))
+    );
+}
+
+#[test]
+fn tinybytes_ord() {
+    let mut values: Vec<&[u8]> = vec![b"\xff", b"a", b"", b"a\0", b"\0", b"ab"];
+    let mut tinies: Vec<TinyBytes<2>> = values
+        .iter()
+        .map(|b| TinyBytes::from_bytes(b).unwrap())
+        .collect();
+    values.sort();
+    tinies.sort();
+    assert_eq!(
+        values,
+        tinies.iter().map(|b| b.as_bytes()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tinybytes_ascii() {
+    let b = TinyBytes::from(tinystr4!("Latn"));
+    assert_eq!(b.deref(), b"Latn");
+    assert_eq!(TinyStr4::try_from(b), Ok(tinystr4!("Latn")));
+
+    let b = TinyBytes::<8>::from(tinystr8!("en"));
+    assert_eq!(b.len(), 2);
+    assert_eq!(TinyStr8::try_from(b), Ok(tinystr8!("en")));
+
+    let b = TinyBytes::<16>::from_bytes(b"a\0b").unwrap();
+    assert_eq!(
+        TinyStr16::try_from(b),
+        Err(Error::InteriorNull { index: 1 })
+    );
+    assert_eq!(
+        TinyStr16::try_from(TinyBytes::<16>::EMPTY),
+        Err(Error::Empty)
+    );
+}
+
+#[test]
+fn tiny_or_empty_basic() {
+    let e4: TinyStr4OrEmpty = "".parse().unwrap();
+    assert!(e4.is_empty());
+    assert_eq!(e4, "");
+    assert_eq!(e4, TinyStr4OrEmpty::default());
+    assert_eq!(e4.into_option(), None);
+    assert_eq!(format!("{:?}", e4), "\"\"");
+
+    let s4: TinyStr4OrEmpty = "abc".parse().unwrap();
+    assert!(!s4.is_empty());
+    assert_eq!(s4.deref(), "abc");
+    assert_eq!(s4.into_option(), Some(tinystr4!("abc")));
+
+    let s8 = TinyStr8OrEmpty::from_bytes(b"abcdefgh").unwrap();
+    assert_eq!(s8, "abcdefgh");
+    assert_eq!(
+        TinyStr8OrEmpty::from_bytes(b"").unwrap(),
+        TinyStr8OrEmpty::EMPTY
+    );
+
+    let s16: TinyStr16OrEmpty = "metamorphosis".parse().unwrap();
+    assert_eq!(s16.as_str(), "metamorphosis");
+
+    assert_eq!(
+        "12345".parse::<TinyStr4OrEmpty>(),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+    assert_eq!(
+        "a\u{0}b".parse::<TinyStr8OrEmpty>(),
+        Err(Error::InteriorNull { index: 1 })
+    );
+    assert_eq!(
+        "\u{4000}".parse::<TinyStr16OrEmpty>(),
+        Err(Error::NonAscii {
+            index: 0,
+            byte: 0xe4
+        })
+    );
+}
+
+#[test]
+fn tiny_or_empty_option() {
+    for value in [None, Some(tinystr8!("en")), Some(tinystr8!("calendar"))].iter() {
+        let converted = TinyStr8OrEmpty::from(*value);
+        assert_eq!(Option::<TinyStr8>::from(converted), *value);
+        assert_eq!(converted, value.as_deref().unwrap_or(""));
+    }
+    assert_eq!(TinyStr4OrEmpty::from(tinystr4!("Latn")), "Latn");
+    assert_eq!(TinyStr16OrEmpty::from(None), TinyStr16OrEmpty::EMPTY);
+}
+
+#[test]
+fn tiny_or_empty_ord() {
+    let mut v: Vec<TinyStr4OrEmpty> = ["zh", "", "aab", "fr", "a"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    v.sort();
+    assert_eq!(
+        v.iter().map(TinyStr4OrEmpty::as_str).collect::<Vec<_>>(),
+        vec!["", "a", "aab", "fr", "zh"]
+    );
+}
+
+macro_rules! test_slicing {
+    ($f:ident, $ty:ident, $or_empty:ident, $max:literal) => {
+        /// Test consistency of TinyStr slicing with str slicing
+        #[test]
+        fn $f() {
+            let mut strings = random_alphanums(1..=$max, 20);
+            strings.extend(
+                ["a", " a", "a ", " ", "\ta\r", "\t a b\r\n"]
+                    .iter()
+                    .map(|s| s.to_string()),
+            );
+            for string in strings.iter().filter(|s| s.len() <= $max) {
+                let tiny: $ty = string.parse().unwrap();
+                let len = string.len();
+                for start in 0..=len + 1 {
+                    for end in 0..=len + 1 {
+                        assert_eq!(
+                            tiny.get(start..end).map(|t| t.to_string()),
+                            string
+                                .get(start..end)
+                                .filter(|s| !s.is_empty())
+                                .map(|s| s.to_string()),
+                        );
+                    }
+                    let prefix = string.get(..start).unwrap_or("?");
+                    let suffix = string.get(start..).unwrap_or("?");
+                    assert_eq!(
+                        tiny.strip_prefix(prefix).map(|t| t.to_string()),
+                        string.strip_prefix(prefix).map(|s| s.to_string())
+                    );
+                    assert_eq!(
+                        tiny.strip_suffix(suffix).map(|t| t.to_string()),
+                        string.strip_suffix(suffix).map(|s| s.to_string())
+                    );
+                    assert_eq!(
+                        tiny.first_n(start).as_deref(),
+                        string.get(..start.min(len)).filter(|s| !s.is_empty())
+                    );
+                    assert_eq!(
+                        tiny.last_n(start).as_deref(),
+                        string.get(len - start.min(len)..).filter(|s| !s.is_empty())
+                    );
+                }
+                for mid in 0..=len {
+                    let (head, tail) = tiny.split_at(mid).unwrap();
+                    assert_eq!(head, &string[..mid]);
+                    assert_eq!(tail, &string[mid..]);
+                }
+                assert_eq!(tiny.split_at(len + 1), None);
+                assert_eq!(tiny.get(..), Some(tiny));
+                assert_eq!(tiny.get(1..=1).as_deref(), string.get(1..=1));
+                assert_eq!(
+                    tiny.trim_ascii().as_deref(),
+                    Some(string.trim_matches(|c: char| c.is_ascii_whitespace()))
+                        .filter(|s| !s.is_empty())
+                );
+                assert_eq!(tiny.strip_prefix(&string[..]), Some($or_empty::EMPTY));
+            }
+        }
+    };
+}
+
+test_slicing!(tiny4_slicing, TinyStr4, TinyStr4OrEmpty, 4);
+test_slicing!(tiny8_slicing, TinyStr8, TinyStr8OrEmpty, 8);
+test_slicing!(tiny16_slicing, TinyStr16, TinyStr16OrEmpty, 16);
+
+macro_rules! test_search {
+    ($f:ident, $ty:ident, $max:literal) => {
+        /// Test consistency of TinyStr searching with str searching
+        #[test]
+        fn $f() {
+            let mut strings = random_alphanums(1..=$max, 20);
+            strings.extend(
+                ["a", "-", "en-US", "en_US", "a-b-c", "--__--"]
+                    .iter()
+                    .map(|s| s.to_string()),
+            );
+            for string in strings.iter().filter(|s| s.len() <= $max) {
+                let tiny: $ty = string.parse().unwrap();
+                for byte in [0, b'a', b'-', b'_', b'U', 0x7f, 0x80, 0xff]
+                    .iter()
+                    .copied()
+                {
+                    let c = char::from(byte);
+                    assert_eq!(tiny.find(byte), string.find(c));
+                    assert_eq!(tiny.rfind(byte), string.rfind(c));
+                    assert_eq!(tiny.contains(byte), string.contains(c));
+                    assert_eq!(tiny.count(byte), string.matches(c).count());
+                }
+                assert_eq!(tiny.find_any(b"-_"), string.find(|c| c == '-' || c == '_'));
+                assert_eq!(tiny.find_any(b""), None);
+                assert_eq!(tiny.find_any(b"\0"), None);
+                for i in 0..=string.len() {
+                    assert!(tiny.starts_with(&string[..i]));
+                    assert!(tiny.ends_with(&string[i..]));
+                }
+                let longer = format!("{}?", string);
+                assert!(!tiny.starts_with(&longer));
+                assert!(!tiny.ends_with(&longer[1..]) || string.ends_with('?'));
+                let with_nul = format!("{}\0", string);
+                assert!(!tiny.starts_with(&with_nul));
+                assert_eq!(tiny.strip_prefix(&with_nul), None);
+            }
+        }
+    };
+}
+
+test_search!(tiny4_search, TinyStr4, 4);
+test_search!(tiny8_search, TinyStr8, 8);
+test_search!(tiny16_search, TinyStr16, 16);
+
+#[test]
+fn tiny_search_const() {
+    const S: TinyStr16 = tinystr16!("zh-Hant-TW");
+    const DASH: Option<usize> = S.find(b'-');
+    const LAST_DASH: Option<usize> = S.rfind(b'-');
+    const PREFIXES: (bool, bool) = (S.starts_with("zh"), S.starts_with("en"));
+    assert_eq!(DASH, Some(2));
+    assert_eq!(LAST_DASH, Some(7));
+    assert_eq!(PREFIXES, (true, false));
+    assert!(S.ends_with(&tinystr4!("-TW")));
+}
+
+macro_rules! test_replace {
+    ($f:ident, $ty:ident, $max:literal) => {
+        /// Test consistency of TinyStr replacement with str replacement
+        #[test]
+        fn $f() {
+            let mut strings = random_alphanums(1..=$max, 20);
+            strings.extend(
+                [
+                    "a",
+                    "-",
+                    "en_US",
+                    "a-b_c",
+                    "--__--",
+                    "Hi, there!",
+                    "a.b:c\t",
+                ]
+                .iter()
+                .map(|s| s.to_string()),
+            );
+            let classes = [
+                (
+                    AsciiClass::PUNCTUATION,
+                    (|c| c.is_ascii_punctuation()) as fn(char) -> bool,
+                ),
+                (AsciiClass::WHITESPACE, |c| c.is_ascii_whitespace()),
+                (AsciiClass::DIGIT, |c| c.is_ascii_digit()),
+                (AsciiClass::UPPERCASE, |c| c.is_ascii_uppercase()),
+                (AsciiClass::CONTROL, |c| c.is_ascii_control()),
+            ];
+            for string in strings.iter().filter(|s| s.len() <= $max) {
+                let tiny: $ty = string.parse().unwrap();
+                for byte in [0, b'a', b'-', b'_', 0x7f, 0x80].iter().copied() {
+                    let c = char::from(byte);
+                    assert_eq!(
+                        tiny.replace_byte(byte, b'+').unwrap(),
+                        string.replace(c, "+").as_str()
+                    );
+                    assert_eq!(tiny.remove_byte(byte), string.replace(c, "").as_str());
+                }
+                for (class, f) in classes.iter() {
+                    assert_eq!(
+                        tiny.map_class(*class, b'#').unwrap(),
+                        string.replace(*f, "#").as_str()
+                    );
+                }
+                let index = string.find('a').unwrap_or(0);
+                assert_eq!(
+                    tiny.replace_byte(b'a', 0),
+                    Err(Error::InteriorNull { index })
+                );
+                assert_eq!(
+                    tiny.replace_byte(b'a', 0x80),
+                    Err(Error::NonAscii { index, byte: 0x80 })
+                );
+                let index = string.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(0);
+                assert_eq!(
+                    tiny.map_class(AsciiClass::ALPHABETIC, 0xff),
+                    Err(Error::NonAscii { index, byte: 0xff })
+                );
+            }
+        }
+    };
+}
+
+test_replace!(tiny4_replace, TinyStr4, 4);
+test_replace!(tiny8_replace, TinyStr8, 8);
+test_replace!(tiny16_replace, TinyStr16, 16);
+
+#[test]
+fn tiny_remove_byte_empty() {
+    assert_eq!(tinystr4!("----").remove_byte(b'-'), TinyStr4OrEmpty::EMPTY);
+    assert_eq!(tinystr16!("-a-").remove_byte(b'-'), "a");
+    const S: TinyStr8OrEmpty = tinystr8!("en-US").remove_byte(b'-');
+    assert_eq!(S, "enUS");
+}
+
+macro_rules! test_remove_byte_positions {
+    ($f:ident, $ty:ident, $max:literal) => {
+        /// Test removing every subset of positions of a full-length string
+        #[test]
+        fn $f() {
+            for len in 1..=$max {
+                for removed in 0..1u32 << len {
+                    let string: String = (0..len)
+                        .map(|i| {
+                            if removed & 1 << i != 0 {
+                                '-'
+                            } else {
+                                (b'a' + i as u8) as char
+                            }
+                        })
+                        .collect();
+                    let tiny: $ty = string.parse().unwrap();
+                    assert_eq!(tiny.remove_byte(b'-'), string.replace('-', "").as_str());
+                }
+            }
+        }
+    };
+}
+
+test_remove_byte_positions!(tiny4_remove_byte_positions, TinyStr4, 4);
+test_remove_byte_positions!(tiny8_remove_byte_positions, TinyStr8, 8);
+test_remove_byte_positions!(tiny16_remove_byte_positions, TinyStr16, 16);
+
+macro_rules! test_split {
+    ($f:ident, $ty:ident, $part:ident, $max:literal) => {
+        /// Test consistency of TinyStr splitting with str splitting
+        #[test]
+        fn $f() {
+            let mut strings = random_alphanums(1..=$max, 20);
+            strings.extend(
+                [
+                    "a",
+                    "-",
+                    "a-",
+                    "-a",
+                    "en-US",
+                    "a-b-c",
+                    "zh-Hant-TW",
+                    "--",
+                    "abcdefghi-j",
+                ]
+                .iter()
+                .map(|s| s.to_string()),
+            );
+            let to_part = |s: &str| s.parse::<$part>();
+            for string in strings.iter().filter(|s| s.len() <= $max) {
+                let tiny: $ty = string.parse().unwrap();
+                assert!(tiny.split_byte(b'-').eq(string.split('-').map(to_part)));
+                assert!(tiny.split_byte(0).eq(string.split('\0').map(to_part)));
+                for n in 0..5 {
+                    assert!(tiny.splitn(n, b'-').eq(string.splitn(n, '-').map(to_part)));
+                }
+                assert_eq!(
+                    tiny.rsplit_once(b'-')
+                        .map(|(a, b)| (a.to_string(), b.to_string())),
+                    string
+                        .rsplit_once('-')
+                        .map(|(a, b)| (a.to_string(), b.to_string()))
+                );
+            }
+        }
+    };
+}
+
+test_split!(tiny4_split, TinyStr4, TinyStr4, 4);
+test_split!(tiny8_split, TinyStr8, TinyStr4, 8);
+test_split!(tiny16_split, TinyStr16, TinyStr8, 16);
+
+#[test]
+fn tiny_split_parts() {
+    let mut parts = tinystr16!("zh-Hant-TW").split_byte(b'-');
+    assert_eq!(parts.next(), Some(Ok(tinystr8!("zh"))));
+    assert_eq!(parts.next(), Some(Ok(tinystr8!("Hant"))));
+    assert_eq!(parts.next(), Some(Ok(tinystr8!("TW"))));
+    assert_eq!(parts.next(), None);
+    assert_eq!(parts.next(), None);
+
+    let mut parts = tinystr16!("abcdefghi--j").split_byte(b'-');
+    assert_eq!(parts.next(), Some(Err(Error::TooLong { len: 9, max: 8 })));
+    assert_eq!(parts.next(), Some(Err(Error::Empty)));
+    assert_eq!(parts.next(), Some(Ok(tinystr8!("j"))));
+    assert_eq!(parts.next(), None);
+}
+
+macro_rules! test_case_insensitive {
+    ($f:ident, $ty:ident, $max:literal) => {
+        /// Test consistency of TinyStr case-insensitive operations with str
+        #[test]
+        fn $f() {
+            use std::collections::hash_map::DefaultHasher;
+            use std::hash::{Hash, Hasher};
+
+            let hash = |value: &AsciiCaseInsensitive<$ty>| {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            };
+            let mut strings = random_alphanums(1..=$max, 20);
+            strings.extend(
+                [
+                    "a", "A", "@", "[", "`", "{", "en-US", "EN-us", "en-USA", "Zz",
+                ]
+                .iter()
+                .map(|s| s.to_string()),
+            );
+            for a in strings.iter().filter(|s| s.len() <= $max) {
+                let tiny_a: $ty = a.parse().unwrap();
+                for b in strings.iter().filter(|s| s.len() <= $max) {
+                    let tiny_b: $ty = b.parse().unwrap();
+                    assert_eq!(tiny_a.eq_ignore_ascii_case(b), a.eq_ignore_ascii_case(b));
+                    assert_eq!(
+                        tiny_a.cmp_ignore_ascii_case(tiny_b),
+                        a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+                    );
+                    let (ci_a, ci_b) = (AsciiCaseInsensitive(tiny_a), AsciiCaseInsensitive(tiny_b));
+                    assert_eq!(ci_a == ci_b, a.eq_ignore_ascii_case(b));
+                    if ci_a == ci_b {
+                        assert_eq!(hash(&ci_a), hash(&ci_b));
+                    }
+                }
+                assert!(!tiny_a.eq_ignore_ascii_case(&format!("{}é", a)));
+                assert!(!tiny_a.eq_ignore_ascii_case(&format!("{}\0", a)));
+                assert!(!tiny_a.eq_ignore_ascii_case(""));
+            }
+        }
+    };
+}
+
+test_case_insensitive!(tiny4_case_insensitive, TinyStr4, 4);
+test_case_insensitive!(tiny8_case_insensitive, TinyStr8, 8);
+test_case_insensitive!(tiny16_case_insensitive, TinyStr16, 16);
+
+#[test]
+fn tiny_case_insensitive_map() {
+    use std::cmp::Ordering;
+    use std::collections::BTreeMap;
+
+    let mut map = BTreeMap::new();
+    map.insert(AsciiCaseInsensitive(tinystr16!("zh-Hant")), 1);
+    map.insert(AsciiCaseInsensitive(tinystr16!("ZH-HANT")), 2);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get(&tinystr16!("zh-hant").into()), Some(&2));
+
+    const ORDER: Ordering = tinystr4!("b").cmp_ignore_ascii_case(tinystr4!("A"));
+    assert_eq!(ORDER, Ordering::Greater);
+    assert_eq!(
+        AsciiCaseInsensitive(tinystr4!("ab")).into_inner(),
+        tinystr4!("ab")
+    );
+}
+
+/// Reference definitions of the ASCII predicates: whether the string must be non-empty,
+/// and whether a byte is allowed at an index, using the `u8::is_ascii_*` methods.
+#[allow(clippy::type_complexity)]
+const PREDICATES: [(&str, bool, fn(usize, u8) -> bool); 12] = [
+    ("alphabetic", true, |_, b| b.is_ascii_alphabetic()),
+    ("alphanumeric", true, |_, b| b.is_ascii_alphanumeric()),
+    ("numeric", true, |_, b| b.is_ascii_digit()),
+    ("lowercase", false, |_, b| !b.is_ascii_uppercase()),
+    ("uppercase", false, |_, b| !b.is_ascii_lowercase()),
+    ("titlecase", false, |i, b| {
+        if i == 0 {
+            !b.is_ascii_lowercase()
+        } else {
+            !b.is_ascii_uppercase()
+        }
+    }),
+    ("alphabetic_lowercase", true, |_, b| b.is_ascii_lowercase()),
+    ("hexdigit", true, |_, b| b.is_ascii_hexdigit()),
+    ("graphic", true, |_, b| b.is_ascii_graphic()),
+    ("punctuation", true, |_, b| b.is_ascii_punctuation()),
+    ("whitespace_free", false, |_, b| !b.is_ascii_whitespace()),
+    ("control_free", false, |_, b| !b.is_ascii_control()),
+];
+
+/// Applies a reference predicate from `PREDICATES` to each byte of `bytes`.
+fn reference_predicate(non_empty: bool, allowed: fn(usize, u8) -> bool, bytes: &[u8]) -> bool {
+    if non_empty && bytes.is_empty() {
+        return false;
+    }
+    for (i, &b) in bytes.iter().enumerate() {
+        if !allowed(i, b) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Calls `f` with every one- and two-byte non-NUL ASCII string, and with each two-byte
+/// string repeated to `len` bytes.
+fn for_each_ascii_pair(len: usize, mut f: impl FnMut(&[u8])) {
+    for a in 1..0x80u8 {
+        f(&[a]);
+        for b in 1..0x80u8 {
+            f(&[a, b]);
+            let repeated: Vec<u8> = [a, b].iter().copied().cycle().take(len).collect();
+            f(&repeated);
+        }
+    }
+}
+
+macro_rules! test_predicates {
+    ($f:ident, $ty:ident, $max:literal) => {
+        #[test]
+        fn $f() {
+            let predicates: [fn($ty) -> bool; 12] = [
+                $ty::is_ascii_alphabetic,
+                $ty::is_ascii_alphanumeric,
+                $ty::is_ascii_numeric,
+                $ty::is_ascii_lowercase,
+                $ty::is_ascii_uppercase,
+                $ty::is_ascii_titlecase,
+                $ty::is_ascii_alphabetic_lowercase,
+                $ty::is_ascii_hexdigit,
+                $ty::is_ascii_graphic,
+                $ty::is_ascii_punctuation,
+                $ty::is_ascii_whitespace_free,
+                $ty::is_ascii_control_free,
+            ];
+            for_each_ascii_pair($max, |bytes| {
+                let tiny = $ty::from_bytes(bytes).unwrap();
+                for (&(name, non_empty, allowed), actual) in
+                    PREDICATES.iter().zip(predicates.iter())
+                {
+                    let expected = reference_predicate(non_empty, allowed, bytes);
+                    assert_eq!(actual(tiny), expected, "{} {:?}", name, tiny);
+                }
+            });
+        }
+    };
+}
+
+test_predicates!(tiny4_predicates, TinyStr4, 4);
+test_predicates!(tiny8_predicates, TinyStr8, 8);
+test_predicates!(tiny16_predicates, TinyStr16, 16);
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn tinyauto_predicates() {
+    let predicates: [fn(&TinyStrAuto) -> bool; 12] = [
+        TinyStrAuto::is_ascii_alphabetic,
+        TinyStrAuto::is_ascii_alphanumeric,
+        TinyStrAuto::is_ascii_numeric,
+        TinyStrAuto::is_ascii_lowercase,
+        TinyStrAuto::is_ascii_uppercase,
+        TinyStrAuto::is_ascii_titlecase,
+        TinyStrAuto::is_ascii_alphabetic_lowercase,
+        TinyStrAuto::is_ascii_hexdigit,
+        TinyStrAuto::is_ascii_graphic,
+        TinyStrAuto::is_ascii_punctuation,
+        TinyStrAuto::is_ascii_whitespace_free,
+        TinyStrAuto::is_ascii_control_free,
+    ];
+    let check = |auto: &TinyStrAuto| {
+        for (&(name, non_empty, allowed), actual) in PREDICATES.iter().zip(predicates.iter()) {
+            let expected = reference_predicate(non_empty, allowed, auto.as_bytes());
+            assert_eq!(actual(auto), expected, "{} {:?}", name, auto);
+        }
+    };
+    for len in [16, 20].iter().copied() {
+        for_each_ascii_pair(len, |bytes| {
+            check(&std::str::from_utf8(bytes).unwrap().parse().unwrap());
+        });
+    }
+    // The variants are public, so an empty heap string can be built directly.
+    check(&TinyStrAuto::Heap(String::new()));
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn tinyautoutf8_predicates() {
+    let predicates: [fn(&TinyStrAutoUtf8) -> bool; 3] = [
+        TinyStrAutoUtf8::is_ascii_alphabetic,
+        TinyStrAutoUtf8::is_ascii_alphanumeric,
+        TinyStrAutoUtf8::is_ascii_numeric,
+    ];
+    let check = |auto: &TinyStrAutoUtf8| {
+        for (&(name, non_empty, allowed), actual) in PREDICATES.iter().zip(predicates.iter()) {
+            let expected = reference_predicate(non_empty, allowed, auto.as_bytes());
+            assert_eq!(actual(auto), expected, "{} {:?}", name, auto);
+        }
+    };
+    for len in [16, 20].iter().copied() {
+        for_each_ascii_pair(len, |bytes| {
+            check(&std::str::from_utf8(bytes).unwrap().into());
+        });
+    }
+    check(&"".into());
+    check(&"veryveryveryveryverylong\u{e9}".into());
+    check(&"\u{663}\u{661}\u{662}".into());
+}
+
+#[test]
+fn tiny_predicates_const() {
+    const S: TinyStr8 = tinystr8!("Hant");
+    const CHECKS: [bool; 3] = [
+        S.is_ascii_titlecase(),
+        S.is_ascii_lowercase(),
+        S.is_ascii_hexdigit(),
+    ];
+    assert_eq!(CHECKS, [true, false, false]);
+}
+
+macro_rules! test_normalize {
+    ($f:ident, $ty:ident, $or_empty:ident, $max:literal) => {
+        /// Test consistency of the fused constructors with from_bytes and the case mappings
+        #[test]
+        fn $f() {
+            let classes = [
+                AsciiClass::ANY,
+                AsciiClass::ALPHABETIC,
+                AsciiClass::ALPHANUMERIC.union(AsciiClass::byte(b'-')),
+            ];
+            let check = |bytes: &[u8]| {
+                for &class in classes.iter() {
+                    let expected = || {
+                        $ty::from_bytes(bytes).and_then(|tiny| {
+                            match tiny.bytes().position(|b| !class.contains(b)) {
+                                None => Ok(tiny),
+                                Some(index) => Err(Error::DisallowedCharacter {
+                                    index,
+                                    byte: bytes[index],
+                                }),
+                            }
+                        })
+                    };
+                    let cases: [(AsciiCase, fn($ty) -> $ty); 4] = [
+                        (AsciiCase::Unchanged, |tiny| tiny),
+                        (AsciiCase::Lowercase, $ty::to_ascii_lowercase),
+                        (AsciiCase::Uppercase, $ty::to_ascii_uppercase),
+                        (AsciiCase::Titlecase, $ty::to_ascii_titlecase),
+                    ];
+                    for &(case, map) in cases.iter() {
+                        assert_eq!(
+                            $ty::from_bytes_with(bytes, class, case),
+                            expected().map(map),
+                            "{:?}",
+                            bytes
+                        );
+                    }
+                }
+                assert_eq!(
+                    $ty::from_bytes_lowercase(bytes),
+                    $ty::from_bytes(bytes).map($ty::to_ascii_lowercase)
+                );
+                assert_eq!(
+                    $ty::from_bytes_alpha_lowercase(bytes),
+                    $ty::from_bytes_with(bytes, AsciiClass::ALPHABETIC, AsciiCase::Lowercase)
+                );
+                assert_eq!(
+                    $or_empty::from_bytes_lowercase(bytes),
+                    $ty::from_bytes_lowercase(bytes).map($or_empty::from)
+                );
+            };
+            for string in random_alphanums(1..=$max, 100) {
+                check(string.as_bytes());
+            }
+            for_each_ascii_pair($max, check);
+            check(b"\0a");
+            check("é".as_bytes());
+            check(&[b'a'; $max + 1]);
+            assert_eq!(
+                $or_empty::from_bytes_alpha_lowercase(b""),
+                Ok($or_empty::EMPTY)
+            );
+        }
+    };
+}
+
+test_normalize!(tiny4_normalize, TinyStr4, TinyStr4OrEmpty, 4);
+test_normalize!(tiny8_normalize, TinyStr8, TinyStr8OrEmpty, 8);
+test_normalize!(tiny16_normalize, TinyStr16, TinyStr16OrEmpty, 16);
+
+macro_rules! test_lossy {
+    ($f:ident, $ty:ident, $max:literal) => {
+        /// Test consistency of the lossy constructors with from_bytes
+        #[test]
+        fn $f() {
+            let check = |bytes: &[u8]| {
+                let kept = &bytes[..bytes.len().min($max)];
+                assert_eq!(
+                    $ty::from_bytes_truncating(bytes),
+                    $ty::from_bytes(kept).map(|tiny| (tiny, bytes.len() > $max))
+                );
+                let replaced: Vec<u8> = bytes
+                    .iter()
+                    .map(|&b| if b == 0 || b >= 0x80 { b'?' } else { b })
+                    .collect();
+                assert_eq!(
+                    $ty::from_bytes_lossy(bytes, b'?'),
+                    $ty::from_bytes(&replaced).map(|tiny| (tiny, replaced != bytes))
+                );
+                if let Ok(text) = std::str::from_utf8(bytes) {
+                    if text.bytes().all(|b| b != 0 && b < 0x80) {
+                        assert_eq!(
+                            $ty::from_str_transliterated(text),
+                            $ty::from_bytes_truncating(bytes)
+                        );
+                    }
+                }
+            };
+            for string in random_alphanums(1..=$max + 2, 100) {
+                check(string.as_bytes());
+            }
+            for_each_ascii_pair($max, check);
+            check(b"");
+            check(b"a\0b");
+            check(b"\xffa\x80");
+            check("Grüße".as_bytes());
+
+            assert_eq!(
+                $ty::from_bytes_truncating(b"a\0bcdefghijklmnopq"),
+                Err(Error::InteriorNull { index: 1 })
+            );
+            assert_eq!(
+                $ty::from_bytes_lossy(b"a\xff", 0x80),
+                Err(Error::NonAscii {
+                    index: 1,
+                    byte: 0x80
+                })
+            );
+            assert_eq!(
+                $ty::from_bytes_lossy(b"ab", 0),
+                Err(Error::InteriorNull { index: 0 })
+            );
+            assert_eq!(
+                $ty::from_str_transliterated("Ça"),
+                Ok(("Ca".parse().unwrap(), true))
+            );
+            assert_eq!(
+                $ty::from_str_transliterated("Łódź"),
+                Ok(("Lodz".parse().unwrap(), true))
+            );
+            assert_eq!(
+                $ty::from_str_transliterated("a\0æ"),
+                Ok(("aae".parse().unwrap(), true))
+            );
+            assert_eq!($ty::from_str_transliterated("日本"), Err(Error::Empty));
+            let (tiny, lossy) = $ty::from_str_transliterated("ßßßßßßßßß").unwrap();
+            assert_eq!(tiny, &"ssssssssssssssssss"[..$max]);
+            assert!(lossy);
+            let (tiny, lossy) = $ty::from_str_transliterated("abßß").unwrap();
+            assert_eq!(tiny, &"abssss"[..$max.min(6)]);
+            assert!(lossy);
+        }
+    };
+}
+
+test_lossy!(tiny4_lossy, TinyStr4, 4);
+test_lossy!(tiny8_lossy, TinyStr8, 8);
+test_lossy!(tiny16_lossy, TinyStr16, 16);
+
+const LANGUAGE: TinyPattern = TinyPattern::new(TinyShape::new(2, 3).all(AsciiClass::ALPHABETIC))
+    .or(TinyShape::new(5, 8).all(AsciiClass::ALPHABETIC));
+const SCRIPT: TinyPattern = TinyPattern::new(
+    TinyShape::new(4, 4)
+        .at(0, AsciiClass::UPPERCASE)
+        .starting_at(1, AsciiClass::LOWERCASE),
+);
+const REGION: TinyPattern = TinyPattern::new(TinyShape::new(2, 2).all(AsciiClass::ALPHABETIC))
+    .or(TinyShape::new(3, 3).all(AsciiClass::DIGIT));
+const VARIANT: TinyPattern = TinyPattern::new(TinyShape::new(5, 8).all(AsciiClass::ALPHANUMERIC))
+    .or(TinyShape::new(4, 4)
+        .at(0, AsciiClass::DIGIT)
+        .all(AsciiClass::ALPHANUMERIC));
+
+fn is_language(s: &str) -> bool {
+    matches!(s.len(), 2..=3 | 5..=8) && s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_script(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() == 4 && b[0].is_ascii_uppercase() && b[1..].iter().all(u8::is_ascii_lowercase)
+}
+
+fn is_region(s: &str) -> bool {
+    (s.len() == 2 && s.bytes().all(|b| b.is_ascii_alphabetic()))
+        || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn is_variant(s: &str) -> bool {
+    let b = s.as_bytes();
+    (matches!(b.len(), 5..=8) || (b.len() == 4 && b[0].is_ascii_digit()))
+        && b.iter().all(u8::is_ascii_alphanumeric)
+}
+
+macro_rules! test_pattern {
+    ($f:ident, $ty:ident, $max:literal) => {
+        /// Test consistency of TinyPattern matching with hand-written predicates
+        #[test]
+        fn $f() {
+            let mut strings = random_alphanums(1..=$max, 100);
+            strings.extend(
+                [
+                    "en", "eng", "e", "Latn", "LATN", "latn", "US", "419", "41", "1994", "a994",
+                    "fonipa",
+                ]
+                .iter()
+                .map(|s| s.to_string()),
+            );
+            let check = |string: &str| {
+                let tiny: $ty = string.parse().unwrap();
+                assert_eq!(
+                    tiny.matches_pattern(&LANGUAGE),
+                    is_language(string),
+                    "{}",
+                    string
+                );
+                assert_eq!(
+                    tiny.matches_pattern(&SCRIPT),
+                    is_script(string),
+                    "{}",
+                    string
+                );
+                assert_eq!(
+                    tiny.matches_pattern(&REGION),
+                    is_region(string),
+                    "{}",
+                    string
+                );
+                assert_eq!(
+                    tiny.matches_pattern(&VARIANT),
+                    is_variant(string),
+                    "{}",
+                    string
+                );
+            };
+            for string in strings.iter().filter(|s| s.len() <= $max) {
+                check(string);
+            }
+            for_each_ascii_pair(4.min($max), |bytes| {
+                check(std::str::from_utf8(bytes).unwrap())
+            });
+        }
+    };
+}
+
+test_pattern!(tiny4_pattern, TinyStr4, 4);
+test_pattern!(tiny8_pattern, TinyStr8, 8);
+test_pattern!(tiny16_pattern, TinyStr16, 16);
+
+#[test]
+fn tiny_pattern_const() {
+    const IS_SCRIPT: bool = tinystr8!("Hant").matches_pattern(&SCRIPT);
+    const IS_REGION: bool = tinystr8!("Hant").matches_pattern(&REGION);
+    assert_eq!((IS_SCRIPT, IS_REGION), (true, false));
+}
+
+#[test]
+#[should_panic]
+fn tiny_shape_too_many_rules() {
+    let _ = TinyShape::new(1, 4)
+        .at(0, AsciiClass::DIGIT)
+        .at(1, AsciiClass::DIGIT)
+        .at(2, AsciiClass::DIGIT)
+        .at(3, AsciiClass::DIGIT)
+        .all(AsciiClass::DIGIT);
+}
+
+define_tinystr_type! {
+    /// A script subtag.
+    pub struct Script(TinyStr4);
+    pattern: SCRIPT;
+    normalize: titlecase;
+    literal: script;
+}
+
+const fn is_region_code(tiny: TinyStr4) -> bool {
+    tiny.matches_pattern(&REGION)
+}
+
+define_tinystr_type! {
+    struct Region(TinyStr4);
+    validate: is_region_code;
+    normalize: uppercase;
+    literal: region;
+}
+
+define_tinystr_type! {
+    struct Variant(TinyStr8);
+    pattern: VARIANT;
+    normalize: lowercase;
+    literal: variant;
+}
+
+#[test]
+fn tiny_defined_type() {
+    const LATN: Script = script!("latn");
+    const US: Region = region!("us");
+    const POSIX: Variant = variant!("POSIX");
+    assert_eq!(LATN, "Latn");
+    assert_eq!(US, "US");
+    assert_eq!(POSIX, "posix");
+
+    assert_eq!("LATN".parse::<Script>(), Ok(LATN));
+    assert_eq!(Script::from_bytes(b"lAtN"), Ok(LATN));
+    assert_eq!(Script::try_from(tinystr4!("latn")), Ok(LATN));
+    assert_eq!(TinyStr4::from(LATN), tinystr4!("Latn"));
+    assert_eq!(LATN.into_tinystr(), tinystr4!("Latn"));
+    assert_eq!("la1n".parse::<Script>(), Err(Error::InvalidFormat));
+    assert_eq!("Lat".parse::<Script>(), Err(Error::InvalidFormat));
+    assert_eq!(
+        "Latin".parse::<Script>(),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+    assert_eq!(
+        "Lät".parse::<Script>(),
+        Err(Error::NonAscii {
+            index: 1,
+            byte: 0xc3
+        })
+    );
+
+    assert_eq!("419".parse::<Region>().unwrap(), "419");
+    assert_eq!("u5".parse::<Region>(), Err(Error::InvalidFormat));
+    assert_eq!("1994".parse::<Variant>().unwrap(), "1994");
+    assert_eq!("abc".parse::<Variant>(), Err(Error::InvalidFormat));
+
+    assert_eq!(LATN.len(), 4);
+    assert_eq!(LATN.as_str(), "Latn");
+    assert_eq!(format!("{}", LATN), "Latn");
+    assert_eq!(format!("{:?}", LATN), format!("{:?}", tinystr4!("Latn")));
+    assert!(region!("DE") < US);
+}
+
+#[cfg(feature = "zerovec")]
+#[test]
+fn tiny_defined_type_ule() {
+    use tinystr::zerovec::ule::{AsULE, ULE};
+
+    type ScriptULE = <Script as AsULE>::ULE;
+
+    let scripts = [script!("Latn"), script!("Cyrl")];
+    let unaligned: Vec<ScriptULE> = scripts.iter().map(|s| s.as_unaligned()).collect();
+    let bytes = ScriptULE::as_byte_slice(&unaligned);
+    assert_eq!(bytes, b"LatnCyrl");
+    let parsed = ScriptULE::parse_byte_slice(bytes).expect("Slice must parse");
+    let recovered: Vec<Script> = parsed.iter().copied().map(Script::from_unaligned).collect();
+    assert_eq!(recovered, scripts);
+
+    assert_eq!(
+        ScriptULE::parse_byte_slice(b"LatnLATN"),
+        Err(Error::InvalidFormat)
+    );
+    assert_eq!(
+        ScriptULE::parse_byte_slice(b"Lat\0"),
+        Err(Error::InvalidFormat)
+    );
+    assert_eq!(
+        ScriptULE::parse_byte_slice(b"La\0n"),
+        Err(Error::InteriorNull { index: 2 })
+    );
+}
+
+#[test]
+fn tiny_exact() {
+    const USD: ExactTinyStr<3> = exact_tinystr!("USD");
+    const AVC1: ExactTinyStr<4> = exact_tinystr!("avc1");
+    assert_eq!(USD, "USD");
+    assert_eq!(USD.len(), 3);
+    assert_eq!(USD.as_array(), b"USD");
+    assert_eq!(format!("{}", USD), "USD");
+    assert_eq!(format!("{:?}", USD), "\"USD\"");
+    assert_eq!(&*AVC1, "avc1");
+    assert!(USD < exact_tinystr!("USN"));
+
+    assert_eq!("USD".parse::<ExactTinyStr<3>>(), Ok(USD));
+    assert_eq!(ExactTinyStr::from_array(*b"USD"), Ok(USD));
+    assert_eq!(
+        "US".parse::<ExactTinyStr<3>>(),
+        Err(Error::LengthMismatch {
+            len: 2,
+            expected: 3
+        })
+    );
+    assert_eq!(
+        "USDX".parse::<ExactTinyStr<3>>(),
+        Err(Error::LengthMismatch {
+            len: 4,
+            expected: 3
+        })
+    );
+    assert_eq!(
+        "".parse::<ExactTinyStr<3>>(),
+        Err(Error::LengthMismatch {
+            len: 0,
+            expected: 3
+        })
+    );
+    assert_eq!(
+        "U\0D".parse::<ExactTinyStr<3>>(),
+        Err(Error::InteriorNull { index: 1 })
+    );
+    assert_eq!(
+        "é1".parse::<ExactTinyStr<3>>(),
+        Err(Error::NonAscii {
+            index: 0,
+            byte: 0xc3
+        })
+    );
+
+    assert_eq!(TinyStr4::from(USD), tinystr4!("USD"));
+    assert_eq!(TinyStr8::from(AVC1), tinystr8!("avc1"));
+    assert_eq!(TinyStr16::from(USD), tinystr16!("USD"));
+    assert_eq!(ExactTinyStr::try_from(tinystr4!("USD")), Ok(USD));
+    assert_eq!(ExactTinyStr::try_from(tinystr8!("avc1")), Ok(AVC1));
+    assert_eq!(
+        ExactTinyStr::<3>::try_from(tinystr16!("USDX")),
+        Err(Error::LengthMismatch {
+            len: 4,
+            expected: 3
+        })
+    );
+}
+
+#[test]
+fn tiny8_split_at_zero() {
+    let (head, tail) = tinystr8!("abc").split_at(0).unwrap();
+    assert_eq!(head, TinyStr8OrEmpty::EMPTY);
+    assert_eq!(tail, "abc");
+}
+
+#[test]
+fn tiny_slicing_const() {
+    const S: TinyStr8 = tinystr8!("en-US");
+    const HEAD: Option<(TinyStr8OrEmpty, TinyStr8OrEmpty)> = S.split_at(2);
+    const REGION: Option<TinyStr8> = S.last_n(2);
+    assert_eq!(HEAD.unwrap().0, "en");
+    assert_eq!(HEAD.unwrap().1, "-US");
+    assert_eq!(REGION.unwrap(), "US");
+}
+
+#[test]
+fn tiny_concat_widening() {
+    assert_eq!(tinystr4!("en") + tinystr4!("-US"), tinystr8!("en-US"));
+    assert_eq!(tinystr4!("abcd") + tinystr4!("efgh"), tinystr8!("abcdefgh"));
+    assert_eq!(
+        tinystr4!("zh") + tinystr8!("-Hant-TW"),
+        tinystr16!("zh-Hant-TW")
+    );
+    assert_eq!(
+        tinystr8!("zh-Hant") + tinystr4!("-TW"),
+        tinystr16!("zh-Hant-TW")
+    );
+    assert_eq!(
+        tinystr8!("abcdefgh") + tinystr8!("ijklmnop"),
+        tinystr16!("abcdefghijklmnop")
+    );
+
+    const S: TinyStr16 = tinystr8!("x").concat(tinystr8!("y"));
+    assert_eq!(S, "xy");
+}
+
+#[test]
+fn tiny_try_concat() {
+    assert_eq!(
+        tinystr4!("ab").try_concat(tinystr4!("cd")),
+        Ok(tinystr4!("abcd"))
+    );
+    assert_eq!(
+        tinystr4!("ab").try_concat(tinystr4!("cde")),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+    assert_eq!(
+        tinystr8!("abcdefg").try_concat(tinystr8!("h")),
+        Ok(tinystr8!("abcdefgh"))
+    );
+    assert_eq!(
+        tinystr16!("metamorphosis").try_concat(tinystr16!("123")),
+        Ok(tinystr16!("metamorphosis123"))
+    );
+    assert_eq!(
+        tinystr16!("metamorphosis").try_concat(tinystr16!("1234")),
+        Err(Error::TooLong { len: 17, max: 16 })
+    );
+}
+
+#[test]
+fn tiny_join() {
+    let parts = [tinystr8!("zh"), tinystr8!("Hant"), tinystr8!("TW")];
+    assert_eq!(
+        TinyStr16::join(&[tinystr16!("zh")], b'-'),
+        Ok(tinystr16!("zh"))
+    );
+    assert_eq!(
+        TinyStr8::join(&parts, b'-'),
+        Err(Error::TooLong { len: 10, max: 8 })
+    );
+    assert_eq!(TinyStr8::join(&parts[..2], b'-'), Ok(tinystr8!("zh-Hant")));
+    assert_eq!(
+        TinyStr8::join(&parts[..2], 0xc3),
+        Err(Error::NonAscii {
+            index: 2,
+            byte: 0xc3
+        })
+    );
+    assert_eq!(TinyStr8::join(&[], b'-'), Err(Error::Empty));
+    assert_eq!(
+        tinystr4!("a").concat_with_separator(b'_', tinystr4!("bc")),
+        Ok(tinystr4!("a_bc"))
+    );
+    assert_eq!(
+        tinystr4!("ab").concat_with_separator(b'_', tinystr4!("bc")),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+    assert_eq!(
+        tinystr16!("en").concat_with_separator(b'-', tinystr16!("US")),
+        Ok(tinystr16!("en-US"))
+    );
+}
+
+macro_rules! test_mutation {
+    ($name:ident, $ty:ident, $full:literal) => {
+        #[test]
+        fn $name() {
+            let max = $full.len();
+            let mut s: $ty = "a".parse().unwrap();
+            assert_eq!(s.pop(), Err(Error::Empty));
+            assert_eq!(s.remove(0), Err(Error::Empty));
+            assert_eq!(s.truncate(0), Err(Error::Empty));
+            assert_eq!(
+                s.try_push(0x80),
+                Err(Error::NonAscii {
+                    index: 1,
+                    byte: 0x80
+                })
+            );
+            assert_eq!(s.try_push(0), Err(Error::InteriorNull { index: 1 }));
+            assert_eq!(s, "a");
+
+            let mut s: $ty = $full.parse().unwrap();
+            let too_long = Err(Error::TooLong { len: max + 1, max });
+            assert_eq!(s.try_push(b'x'), too_long);
+            assert_eq!(s.try_insert(0, b'x'), too_long);
+            assert_eq!(s.pad_start(max + 1, b'x'), too_long);
+            assert_eq!(s.pop(), Ok($full.as_bytes()[$full.len() - 1]));
+            assert_eq!(s, &$full[..$full.len() - 1]);
+            assert_eq!(s.try_push(b'!'), Ok(()));
+            assert_eq!(s.remove(0), Ok($full.as_bytes()[0]));
+            assert_eq!(s.try_insert(0, b'^'), Ok(()));
+            assert_eq!(s.try_set($full.len() - 1, b'$'), Ok(()));
+            assert_eq!(
+                s.try_set(max, b'$'),
+                Err(Error::IndexOutOfBounds {
+                    index: max,
+                    len: max
+                })
+            );
+            let expected = format!("^{}$", &$full[1..$full.len() - 1]);
+            assert_eq!(s, expected.as_str());
+
+            let mut s: $ty = "Ab".parse().unwrap();
+            assert_eq!(s.try_insert(2, b'c'), Ok(()));
+            assert_eq!(s.try_insert(3, 0x7f), Ok(()));
+            assert_eq!(
+                s.try_insert(5, b'c'),
+                Err(Error::IndexOutOfBounds { index: 5, len: 4 })
+            );
+            assert_eq!(s, "Abc\x7f");
+            assert_eq!(s.remove(3), Ok(0x7f));
+            assert_eq!(s.truncate(20), Ok(()));
+            assert_eq!(s, "Abc");
+            s.make_ascii_uppercase();
+            assert_eq!(s, "ABC");
+            s.make_ascii_lowercase();
+            assert_eq!(s, "abc");
+            s.make_ascii_titlecase();
+            assert_eq!(s, "Abc");
+            assert_eq!(s.truncate(1), Ok(()));
+            assert_eq!(s.pad_end(4, b'_'), Ok(()));
+            assert_eq!(s, "A___");
+            assert_eq!(s.pad_end(2, b'_'), Ok(()));
+            assert_eq!(s.pad_start(2, b'0'), Ok(()));
+            assert_eq!(s, "A___");
+            assert_eq!(s.truncate(2), Ok(()));
+            assert_eq!(
+                s.pad_start(4, 0xff),
+                Err(Error::NonAscii {
+                    index: 0,
+                    byte: 0xff
+                })
+            );
+            assert_eq!(s.pad_start(4, b'0'), Ok(()));
+            assert_eq!(s, "00A_");
+
+            let mut s: $ty = "1".parse().unwrap();
+            assert_eq!(s.pad_start($full.len(), b'0'), Ok(()));
+            assert_eq!(s.len(), $full.len());
+            assert!(s.ends_with("01"));
+        }
+    };
+}
+
+test_mutation!(tiny4_mutation, TinyStr4, "abcd");
+test_mutation!(tiny8_mutation, TinyStr8, "abcdefgh");
+test_mutation!(tiny16_mutation, TinyStr16, "abcdefghijklmnop");
+
+macro_rules! test_iter {
+    ($name:ident, $ty:ident, $int:ident, $max:literal) => {
+        #[test]
+        fn $name() {
+            for text in &[
+                "a",
+                "ab",
+                "Test",
+                "Testing",
+                "Metamorphosis",
+                "abcdefghijklmnop",
+            ] {
+                if text.len() > $max {
+                    continue;
+                }
+                let s: $ty = text.parse().unwrap();
+                assert_eq!(s.len(), text.len());
+                assert!(s.bytes().eq(text.bytes()));
+                assert!(s.bytes().rev().eq(text.bytes().rev()));
+                assert!(s.chars().eq(text.chars()));
+                assert!(s.chars().rev().eq(text.chars().rev()));
+                assert_eq!(s.bytes().len(), text.len());
+                assert_eq!(s.bytes().last(), text.bytes().last());
+                for i in 0..=$max {
+                    assert_eq!(s.byte_at(i), text.as_bytes().get(i).copied());
+                }
+                assert_eq!(s.byte_at(usize::MAX), None);
+
+                // The iterator holds the word of the TinyStr, with no widening.
+                let mut bytes: tinystr::Bytes<$int> = s.bytes();
+                let mut expected = text.bytes();
+                while bytes.len() > 0 {
+                    assert_eq!(bytes.next(), expected.next());
+                    assert_eq!(bytes.next_back(), expected.next_back());
+                    assert_eq!(bytes.len(), expected.len());
+                }
+                assert_eq!(bytes.next(), None);
+                assert_eq!(bytes.next_back(), None);
+            }
+        }
+    };
+}
+
+test_iter!(tiny4_iter, TinyStr4, u32, 4);
+test_iter!(tiny8_iter, TinyStr8, u64, 8);
+test_iter!(tiny16_iter, TinyStr16, u128, 16);
+
+#[test]
+fn tiny_const_len() {
+    const S: TinyStr8 = tinystr8!("Testing");
+    const LEN: usize = S.len();
+    const FIRST: Option<u8> = S.byte_at(0);
+    assert_eq!(LEN, 7);
+    assert_eq!(FIRST, Some(b'T'));
+}
+
+#[test]
+fn tiny_builder() {
+    let mut builder = TinyStrBuilder::<4>::new();
+    assert!(builder.is_empty());
+    assert_eq!(builder.build(), Err(Error::Empty));
+    let prefix = 'x';
+    write!(builder, "{}{:02}", prefix, 5).unwrap();
+    assert_eq!(builder, "x05");
+    assert!(write!(builder, "ab").is_err());
+    assert!(write!(builder, "é").is_err());
+    assert!(write!(builder, "\0").is_err());
+    assert_eq!(builder.len(), 3);
+    write!(builder, "!").unwrap();
+    assert_eq!(builder.build(), Ok(tinystr4!("x05!")));
+    builder.clear();
+    assert_eq!(builder, "");
+
+    let mut builder = TinyStrBuilder::<16>::default();
+    for i in 0..4 {
+        write!(builder, "{:03}-", i).unwrap();
+    }
+    assert_eq!(builder.build(), Ok(tinystr16!("000-001-002-003-")));
+    assert_eq!(format!("{:?}", builder), "\"000-001-002-003-\"");
+
+    let mut builder = TinyStrBuilder::<8>::new();
+    assert_eq!(builder.try_extend(b"abc".iter().copied()), Ok(()));
+    assert_eq!(
+        builder.try_push(0x80),
+        Err(Error::NonAscii {
+            index: 3,
+            byte: 0x80
+        })
+    );
+    assert_eq!(builder.try_push(0), Err(Error::InteriorNull { index: 3 }));
+    assert_eq!(
+        builder.try_extend(b"defghi".iter().copied()),
+        Err(Error::TooLong { len: 9, max: 8 })
+    );
+    assert_eq!(builder.build(), Ok(tinystr8!("abcdefgh")));
+}
+
+#[test]
+fn tiny_try_from_iter() {
+    assert_eq!(TinyStr4::try_from_iter("ab".bytes()), Ok(tinystr4!("ab")));
+    assert_eq!(
+        TinyStr8::try_from_iter("abc".bytes().rev()),
+        Ok(tinystr8!("cba"))
+    );
+    assert_eq!(
+        TinyStr16::try_from_iter(b'a'..=b'p'),
+        Ok(tinystr16!("abcdefghijklmnop"))
+    );
+    assert_eq!(
+        TinyStr16::try_from_iter(b'a'..=b'q'),
+        Err(Error::TooLong { len: 17, max: 16 })
+    );
+    assert_eq!(
+        TinyStr4::try_from_iter(std::iter::repeat(b'a')),
+        Err(Error::TooLong { len: 5, max: 4 })
+    );
+    assert_eq!(TinyStr4::try_from_iter(None), Err(Error::Empty));
+    assert_eq!(
+        TinyStr4::try_from_iter(Some(0xff)),
+        Err(Error::NonAscii {
+            index: 0,
+            byte: 0xff
+        })
+    );
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn supports_std_error() {
@@ -617,21 +2382,116 @@ fn tinyauto_basic() {
 #[cfg(any(feature = "std", feature = "alloc"))]
 #[test]
 fn tinyauto_nonascii() {
-    assert_eq!("\u{4000}".parse::<TinyStrAuto>(), Err(Error::NonAscii));
+    assert_eq!(
+        "\u{4000}".parse::<TinyStrAuto>(),
+        Err(Error::NonAscii {
+            index: 0,
+            byte: 0xe4
+        })
+    );
     assert_eq!(
         "veryveryveryveryverylong\u{4000}".parse::<TinyStrAuto>(),
-        Err(Error::NonAscii)
+        Err(Error::NonAscii {
+            index: 24,
+            byte: 0xe4
+        })
     );
 }
 
-#[cfg(feature = "macros")]
-const TS: TinyStr8 = tinystr::macros::tinystr8!("test");
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn tinyauto_errors() {
+    assert_eq!("".parse::<TinyStrAuto>(), Err(Error::Empty));
+    assert_eq!(
+        "a\0b".parse::<TinyStrAuto>(),
+        Err(Error::InteriorNull { index: 1 })
+    );
+    assert_eq!(
+        "veryveryveryveryvery\0long".parse::<TinyStrAuto>(),
+        Err(Error::InteriorNull { index: 20 })
+    );
+}
 
-#[cfg(feature = "macros")]
+#[cfg(any(feature = "std", feature = "alloc"))]
 #[test]
-fn tinystr_macros() {
-    use tinystr::macros::*;
+fn tinyautoutf8_basic() {
+    let s1: TinyStrAutoUtf8 = "abc".into();
+    assert!(matches!(s1, TinyStrAutoUtf8::Tiny(_)));
+    assert_eq!(s1, "abc");
+
+    let s2: TinyStrAutoUtf8 = "veryveryveryveryverylong".into();
+    assert!(matches!(s2, TinyStrAutoUtf8::Heap(_)));
+    assert_eq!(s2, "veryveryveryveryverylong");
+
+    let s3: TinyStrAutoUtf8 = "".parse().unwrap();
+    assert!(matches!(s3, TinyStrAutoUtf8::Heap(_)));
+    assert_eq!(s3, "");
+
+    let s4: TinyStrAutoUtf8 = TinyStrAuto::from_str("abc").unwrap().into();
+    assert_eq!(s4, s1);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn tinyautoutf8_nonascii() {
+    let s1: TinyStrAutoUtf8 = "\u{4000}".into();
+    assert!(matches!(s1, TinyStrAutoUtf8::Heap(_)));
+    assert_eq!(s1, "\u{4000}");
+    assert!(!s1.is_ascii());
+
+    let s2: TinyStrAutoUtf8 = String::from("veryveryveryveryverylong\u{4000}").into();
+    assert!(matches!(s2, TinyStrAutoUtf8::Heap(_)));
+    assert_eq!(s2, "veryveryveryveryverylong\u{4000}");
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn tinyautoutf8_ascii_semantics() {
+    let tiny: TinyStrAutoUtf8 = "aBc1".into();
+    assert!(tiny.is_ascii_alphanumeric());
+    assert!(!tiny.is_ascii_alphabetic());
+    assert!(!tiny.is_ascii_numeric());
+    assert_eq!(tiny.to_ascii_lowercase(), "abc1");
+    assert_eq!(tiny.to_ascii_uppercase(), "ABC1");
+    assert_eq!(tiny.to_ascii_titlecase(), "Abc1");
+
+    let heap: TinyStrAutoUtf8 = "éCOLE".into();
+    assert!(!heap.is_ascii_alphabetic());
+    assert!(!heap.is_ascii_alphanumeric());
+    assert_eq!(heap.to_ascii_lowercase(), "école");
+    assert_eq!(heap.to_ascii_uppercase(), "éCOLE");
+    assert_eq!(heap.to_ascii_titlecase(), "école");
+
+    let long: TinyStrAutoUtf8 = "veryveryveryveryverylong".into();
+    assert!(long.is_ascii_alphabetic());
+    assert_eq!(long.to_ascii_titlecase(), "Veryveryveryveryverylong");
+
+    let empty: TinyStrAutoUtf8 = "".into();
+    assert!(empty.is_ascii());
+    assert!(!empty.is_ascii_alphabetic());
+    assert!(!empty.is_ascii_numeric());
+}
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn tinyautoutf8_ord() {
+    let mut v: Vec<TinyStrAutoUtf8> = vec![
+        "zh".into(),
+        "veryveryveryveryverylong".into(),
+        "été".into(),
+        "aab".into(),
+    ];
+    v.sort();
+    assert_eq!(
+        v.iter().map(TinyStrAutoUtf8::as_str).collect::<Vec<_>>(),
+        vec!["aab", "veryveryveryveryverylong", "zh", "été"]
+    );
+}
+
+const TS: TinyStr8 = tinystr8!("test");
+
+#[test]
+fn tinystr_macros() {
     let x: TinyStr8 = "test".parse().unwrap();
     assert_eq!(TS, x);
 
@@ -1,8 +1,7 @@
-use serde_json;
 use tinystr::*;
 
 macro_rules! test_roundtrip {
-    ($f:ident, $ty:ident, $val:expr, $bincode:expr) => {
+    ($f:ident, $ty:ty, $val:expr, $bincode:expr) => {
         #[test]
         fn $f() {
             let tiny: $ty = $val.parse().unwrap();
@@ -49,3 +48,115 @@ test_roundtrip!(
         111, 110, 103, 115, 116, 114, 105, 110, 103
     ]
 );
+test_roundtrip!(
+    test_roundtripautoutf8_1,
+    TinyStrAutoUtf8,
+    "shortstring",
+    [11, 0, 0, 0, 0, 0, 0, 0, 115, 104, 111, 114, 116, 115, 116, 114, 105, 110, 103]
+);
+test_roundtrip!(
+    test_roundtripautoutf8_2,
+    TinyStrAutoUtf8,
+    "日本",
+    [6, 0, 0, 0, 0, 0, 0, 0, 230, 151, 165, 230, 156, 172]
+);
+test_roundtrip!(
+    test_roundtriputf8,
+    TinyUtf8Str<8>,
+    "日本",
+    [6, 0, 0, 0, 0, 0, 0, 0, 230, 151, 165, 230, 156, 172]
+);
+test_roundtrip!(test_roundtrip4_empty, TinyStr4OrEmpty, "", [0, 0, 0, 0]);
+test_roundtrip!(
+    test_roundtrip8_empty,
+    TinyStr8OrEmpty,
+    "",
+    [0, 0, 0, 0, 0, 0, 0, 0]
+);
+test_roundtrip!(
+    test_roundtrip16_or_empty,
+    TinyStr16OrEmpty,
+    "verylongstring",
+    [118, 101, 114, 121, 108, 111, 110, 103, 115, 116, 114, 105, 110, 103, 0, 0]
+);
+
+#[test]
+fn test_roundtrip_bytes() {
+    let tiny = TinyBytes::<4>::from_bytes(b"\0a\xff").unwrap();
+    let json_string = serde_json::to_string(&tiny).unwrap();
+    assert_eq!(json_string, "[0,97,255]");
+    let recover: TinyBytes<4> = serde_json::from_str(&json_string).unwrap();
+    assert_eq!(tiny, recover);
+    assert!(serde_json::from_str::<TinyBytes<2>>(&json_string).is_err());
+
+    let bin = bincode::serialize(&tiny).unwrap();
+    assert_eq!(bin, [3, 0, 0, 0, 0, 0, 0, 0, 0, 97, 255]);
+    let debin: TinyBytes<4> = bincode::deserialize(&bin).unwrap();
+    assert_eq!(tiny, debin);
+    assert!(bincode::deserialize::<TinyBytes<2>>(&bin).is_err());
+}
+
+#[test]
+fn test_roundtrip_exact() {
+    let exact = exact_tinystr!("USD");
+    let json_string = serde_json::to_string(&exact).unwrap();
+    assert_eq!(json_string, "\"USD\"");
+    let recover: ExactTinyStr<3> = serde_json::from_str(&json_string).unwrap();
+    assert_eq!(exact, recover);
+    assert!(serde_json::from_str::<ExactTinyStr<4>>(&json_string).is_err());
+
+    let bin = bincode::serialize(&exact).unwrap();
+    let debin: ExactTinyStr<3> = bincode::deserialize(&bin).unwrap();
+    assert_eq!(exact, debin);
+}
+
+define_tinystr_type! {
+    struct Script(TinyStr4);
+    validate: TinyStr4::is_ascii_alphabetic;
+    normalize: titlecase;
+    literal: script;
+}
+
+#[test]
+fn test_roundtrip_defined_type() {
+    let json_string = serde_json::to_string(&script!("latn")).unwrap();
+    assert_eq!(json_string, "\"Latn\"");
+    let recover: Script = serde_json::from_str("\"LATN\"").unwrap();
+    assert_eq!(recover, script!("Latn"));
+    assert!(serde_json::from_str::<Script>("\"La1n\"").is_err());
+
+    let bin = bincode::serialize(&recover).unwrap();
+    assert_eq!(bin, [76, 97, 116, 110]);
+    let debin: Script = bincode::deserialize(&bin).unwrap();
+    assert_eq!(recover, debin);
+}
+
+#[test]
+fn test_deserialize_errors() {
+    fn message<T: std::fmt::Debug>(result: Result<T, bincode::Error>) -> String {
+        result.unwrap_err().to_string()
+    }
+
+    assert_eq!(
+        message(bincode::deserialize::<TinyStr4>(&[0, 0, 0, 0])),
+        "string is empty"
+    );
+    assert_eq!(
+        message(bincode::deserialize::<TinyStr4>(&[101, 0, 110, 0])),
+        "NUL byte at index 1"
+    );
+    assert_eq!(
+        message(bincode::deserialize::<TinyStr8>(&[
+            101, 110, 0xc3, 0, 0, 0, 0, 0
+        ])),
+        "non-ASCII byte 0xc3 at index 2"
+    );
+    let json = serde_json::from_str::<TinyStr16>("\"\"").unwrap_err();
+    assert!(json.to_string().starts_with("string is empty"));
+    let json = serde_json::from_str::<TinyStr4>("\"Latin\"").unwrap_err();
+    assert!(json
+        .to_string()
+        .starts_with("string is too long: 5 bytes, expected at most 4 bytes"));
+    let json = serde_json::from_str::<TinyStrAuto>("\"a\\u0000b\"").unwrap_err();
+    assert!(json.to_string().starts_with("NUL byte at index 1"));
+}